@@ -1,6 +1,6 @@
 use egui::{Context, CentralPanel, SidePanel, TopBottomPanel, ScrollArea, RichText, Key};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, hash_map::DefaultHasher};
+use std::collections::{BTreeSet, HashMap, hash_map::DefaultHasher};
 use std::sync::Arc;
 use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
@@ -8,7 +8,7 @@ use crate::{pose::Pose, prompt::PromptGenerator,
     canvas3d::{draw_3d_canvas, Camera3D},
     json_loader::{OptionsLibrary, StylesLibrary, SettingsLibrary, GenericLibrary}};
 
-fn get_app_dir() -> PathBuf {
+pub fn get_app_dir() -> PathBuf {
     let base = if cfg!(target_os = "windows") { std::env::var("APPDATA").ok() }
         else if cfg!(target_os = "macos") { std::env::var("HOME").ok().map(|h| format!("{}/Library/Application Support", h)) }
         else                              { std::env::var("HOME").ok().map(|h| format!("{}/.config", h)) };
@@ -18,8 +18,22 @@ fn get_app_dir() -> PathBuf {
     p
 }
 
-fn saves_file() -> PathBuf { get_app_dir().join("promptpuppet_saves.json") }
+/// JSON Lines: a `{"version":N}` header, then one compact `SavedState` per line.
+fn saves_file() -> PathBuf { get_app_dir().join("promptpuppet_saves.jsonl") }
+/// Old single pretty-printed array; read once and migrated to `saves_file`.
+fn legacy_saves_file() -> PathBuf { get_app_dir().join("promptpuppet_saves.json") }
+const SAVES_VERSION: u32 = 1;
 fn theme_file() -> PathBuf { get_app_dir().join("promptpuppet_theme.json") }
+fn startup_file() -> PathBuf { get_app_dir().join("promptpuppet_startup.json") }
+fn boilerplate_file() -> PathBuf { get_app_dir().join("promptpuppet_boilerplate.json") }
+/// Live state written every `AUTOSAVE_SECS` while it changes; removed on a clean exit.
+fn recovery_file() -> PathBuf { get_app_dir().join("promptpuppet_recovery.json") }
+const AUTOSAVE_SECS: f32 = 10.0;
+
+/// Toasts stacked on the canvas at once; older ones drop off the top.
+const MAX_TOASTS: usize = 4;
+/// Activity log entries kept for the session.
+const MAX_LOG: usize = 200;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct OptionsData {
@@ -85,6 +99,10 @@ pub struct PresetItem {
     #[serde(skip)] pub pose_data: Option<Pose>,
     pub prompt: Option<String>,
     pub allow_custom: bool,
+    #[serde(skip)] pub camera: Option<crate::json_loader::CameraHint>,
+    #[serde(default)] pub tags: Vec<String>,
+    /// Library category the item is grouped under in the selector.
+    #[serde(default)] pub category: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -120,6 +138,13 @@ pub struct AppState {
     #[serde(default)] pub video_mode:  bool,
     #[serde(default)] pub selections:  HashMap<String, SelectionState>,
     #[serde(default)] pub custom_data: HashMap<String, String>,
+    /// Video-mode pose keyframes, in playback order.
+    #[serde(default)] pub keyframes:   Vec<Pose>,
+    /// Panel titles left out of the generated prompt (their selections are kept).
+    #[serde(default)] pub muted_sections: BTreeSet<String>,
+    /// Id of the pose preset the current pose was picked from (and possibly
+    /// edited since); `None` for random, pasted or imported poses.
+    #[serde(default)] pub pose_origin: Option<String>,
 }
 
 impl std::hash::Hash for AppState {
@@ -138,12 +163,44 @@ impl std::hash::Hash for AppState {
         let mut v: Vec<_> = self.custom_data.iter().collect();
         v.sort_unstable_by_key(|(k, _)| k.as_str());
         for (k, d) in v { k.hash(state); d.hash(state); }
+        self.keyframes.hash(state);
+        self.muted_sections.hash(state);
+        self.pose_origin.hash(state);
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct SavedState { pub name: String, pub timestamp: String, pub state: AppState }
 
+/// Portable recipe file: the full state plus the prompt it produced, so someone
+/// else can re-import it and get the same prompt with an editable pose.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Bundle {
+    pub version:        u32,
+    pub prompt:         String,
+    #[serde(default)]
+    pub pose_is_manual: bool,
+    pub state:          AppState,
+}
+
+impl Bundle {
+    /// Bump when the layout changes incompatibly; newer files are refused on import.
+    pub const VERSION: u32 = 1;
+}
+
+/// Shareable style/quality baseline: only the `options` and `settings` maps of
+/// an `AppState`, no pose or selections. Imported by merging, key by key.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct SettingsFile {
+    pub version:  u32,
+    #[serde(default)] pub options:  HashMap<String, OptionsData>,
+    #[serde(default)] pub settings: HashMap<String, Settings>,
+}
+
+impl SettingsFile {
+    pub const VERSION: u32 = 1;
+}
+
 pub struct PromptPuppetApp {
     pub state:            AppState,
     pub libraries:        HashMap<String, OptionsLibrary>,
@@ -152,46 +209,244 @@ pub struct PromptPuppetApp {
     pub preset_metadata:  HashMap<String, PresetMetadata>,
     pub default_pose:     Pose,
     pub dragging_joint_3d: Option<String>,
+    /// Up to two joints picked with shift+click for the canvas measuring tape.
+    pub measure_joints:   Vec<String>,
     pub search:           HashMap<String, String>,
     pub popup_open:       HashMap<String, bool>,
+    /// Active tag filter per preset selector (the 🏷 chips in its popup).
+    pub tag_filter:       HashMap<String, String>,
     pub generated_prompt: String,
-    pub status_message:   String,
-    pub status_timer:     f32,
+    /// Live status toasts as (message, seconds left), oldest first; at most `MAX_TOASTS`.
+    pub toasts:           Vec<(String, f32)>,
+    /// Every status message this session, oldest first; capped at `MAX_LOG`.
+    pub activity_log:     Vec<String>,
+    /// The 📜 activity log window is open.
+    pub show_log:         bool,
     pub ui_config:        Arc<crate::json_loader::UiConfig>,
     state_hash:           u64,
     pub dark_mode:        bool,
+    /// Canvas color scheme; independent of `dark_mode`.
+    pub canvas_theme:     crate::canvas3d::CanvasTheme,
+    /// Thick zoom-scaled capsule bones instead of thin lines.
+    pub capsule_bones:    bool,
+    /// Composition guides over the canvas (thirds, crosshair, ground line).
+    pub show_guides:      bool,
     pub save_dialog:      Option<String>,
     pub load_dialog:      bool,
+    /// "Reset pose?" confirmation is open.
+    pub reset_dialog:     bool,
+    /// Name typed into the save dialog that already exists; confirming replaces it.
+    pub overwrite_dialog: Option<String>,
+    /// Controls/shortcuts overlay (F1 or ❓). Opens by itself until first dismissed.
+    pub show_help:        bool,
+    /// The help overlay has been dismissed once; persisted with the theme.
+    help_seen:            bool,
     pub saves:            Vec<SavedState>,
+    /// Unsaved state from a session that didn't exit cleanly, awaiting the
+    /// restore/discard answer.
+    recovery:             Option<Recovery>,
+    /// When the state first changed since the last crash-recovery write.
+    autosave_since:       Option<std::time::Instant>,
+    /// `state_hash` at the last recovery write, to skip unchanged rewrites.
+    autosave_hash:        u64,
     pub camera_3d:        Camera3D,
     /// True once the user has manually dragged a joint. Cleared when a preset
     /// or reset restores a known pose — at which point the JSON prompt returns.
     pub pose_is_manual:   bool,
     /// Accumulated time since last prompt rebuild (used to throttle during drag).
     prompt_throttle:      f32,
+    /// The state changed during a drag but the prompt hasn't caught up yet;
+    /// flushed at the next throttle tick or when the drag is released.
+    prompt_dirty:         bool,
+    /// Timeline scrub position in keyframe units (1.5 = halfway between #2 and #3).
+    pub timeline_pos:     f32,
+    /// Asset load failures collected at startup; shown in a dismissible banner.
+    pub load_errors:      Vec<String>,
+    /// Seed for the next "🎲 Random Pose"; advances after each roll.
+    pub random_seed:      u64,
+    /// Adds approximate angles to the semantic pose description.
+    pub verbose_pose:     bool,
+    /// Mention even weight for planted, symmetric stances.
+    pub even_weight:      bool,
+    /// Write manual pose descriptions as Danbooru tags instead of prose.
+    pub danbooru_tags:    bool,
+    /// Whole-prompt layout: per-panel mix, flat tag list, or sentences.
+    pub prompt_style:     crate::prompt::PromptStyle,
+    /// Prompt prefix/suffix, saved to promptpuppet_boilerplate.json.
+    pub boilerplate:      Boilerplate,
+    /// Edited presets keep their own prompt plus a short list of changes.
+    pub preset_delta:     bool,
+    /// Draw the semantics metrics overlay on the canvas (🐞 Stats toggle).
+    pub show_pose_stats:  bool,
+    /// Label bones with their length on the canvas (📏 Lengths toggle).
+    pub show_bone_lengths: bool,
+    /// Gaze arrow and chest chevron on the canvas (🧭 Facing toggle).
+    pub show_facing:      bool,
+    /// Limbs left undrawn on the canvas and in image export (👁 Limbs menu).
+    pub hidden_limbs:     std::collections::HashSet<crate::canvas3d::Limb>,
+    /// Joint drags rotate the bone about its parent instead of solving IK (🦾 FK toggle).
+    pub fk_drag:          bool,
+    /// Neck and waist drags keep the crotch fixed and bend the spine (📌 toggle).
+    pub pin_root:         bool,
+    /// Startup pose choice, saved to promptpuppet_startup.json.
+    pub startup_pose:     StartupPose,
+    /// "💪 Strength" slider value; snaps back to 1.0 once applied.
+    pub pose_strength:    f32,
+    /// Pose captured when a strength drag began, so the preview is non-destructive.
+    strength_base:        Option<Pose>,
+    /// Photo shown faintly behind the figure for tracing (🖼 Reference).
+    pub reference_image:  Option<egui::TextureHandle>,
+    /// Opacity of `reference_image`, 0–1.
+    pub reference_opacity: f32,
+    /// Pose from before the last preset pick, for revert and blending.
+    pub preset_stash:     Option<PresetStash>,
+    /// "🔀 Blend" slider: 0 = pose before the preset, 1 = the preset.
+    pub preset_blend:     f32,
+    /// "🔃 Turn" slider angle in degrees; snaps back to 0 once applied.
+    pub body_rotation:    f32,
+    /// Pose captured when a turn drag began, so the preview is non-destructive.
+    rotation_base:        Option<Pose>,
+    /// Side length of "🖼 Export Image" PNGs.
+    pub snapshot_px:      u32,
+    /// Show the prompt split by panel, each with its own copy button.
+    pub show_sections:    bool,
+    /// Per-panel prompt sections; only filled while `show_sections` is on.
+    pub prompt_sections:  Vec<(String, String)>,
+    /// Frames left to wait for the clipboard paste event "📋 Paste Pose"
+    /// requested: its own frame and the next. A paste that never arrives
+    /// (empty or non-text clipboard) then stops counting as requested.
+    awaiting_paste:       u8,
 
     // ── 🕺 Easter egg: Ctrl+Shift+D → Dance Mode ─────────────────────────────
+    // The same clock also plays the video-mode loops (breathing, waving, walk).
     pub dance_mode:       bool,
     pub dance_time:       f32,
     /// Snapshot of the pose taken when dance mode starts so we can restore it.
     pub pre_dance_pose:   Option<Pose>,
+    /// Animation being played while `dance_mode` is on.
+    pub loop_anim:        crate::ftlz::LoopAnim,
+    /// Loop picked in the keyframe panel's dropdown.
+    pub loop_choice:      crate::ftlz::LoopAnim,
 }
 
 #[derive(Serialize, Deserialize)]
-struct ThemePref { dark_mode: bool }
+struct ThemePref {
+    dark_mode: bool,
+    #[serde(default)]
+    canvas_theme: crate::canvas3d::CanvasTheme,
+    #[serde(default)]
+    capsule_bones: bool,
+    #[serde(default)]
+    show_guides: bool,
+    #[serde(default)]
+    help_seen: bool,
+}
+
+/// Which pose the app opens with.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StartupPose {
+    /// The first preset library's `default` entry.
+    #[default]
+    LibraryDefault,
+    /// A specific preset, which also becomes the "🔄 Reset Pose" target.
+    Preset { library: String, id: String },
+    /// Whatever was on the canvas when the app last closed.
+    LastUsed,
+}
+
+/// What a preset pose replaced, so "↩ Revert Preset" can bring it back and
+/// "🔀 Blend" can mix the two.
+pub struct PresetStash {
+    /// Pose, origin, manual flag and selections from before the preset.
+    pub before:         Pose,
+    pub origin:         Option<String>,
+    pub pose_is_manual: bool,
+    pub selections:     HashMap<String, SelectionState>,
+    /// The preset pose as applied; a live pose still equal to it means the user
+    /// is only auditioning presets, so the next one keeps the same `before`.
+    pub applied:        Pose,
+}
+
+/// Text wrapped around every generated prompt (📎 Boilerplate).
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Boilerplate {
+    #[serde(default)]
+    pub prefix: String,
+    #[serde(default)]
+    pub suffix: String,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct StartupPref {
+    #[serde(default)]
+    pose: StartupPose,
+    /// Written on exit while `pose` is `LastUsed`.
+    #[serde(default)]
+    last_pose: Option<Pose>,
+}
+
+/// Autosaved working state, offered back at startup after a crash.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Recovery {
+    #[serde(default)]
+    pub pose_is_manual: bool,
+    pub state:          AppState,
+}
 
-fn load_or_warn<T: for<'de> serde::Deserialize<'de>>(name: &str) -> Option<T> {
-    crate::json_loader::load(name).map_err(|e| eprintln!("Warning: {e}")).ok()
+/// A recovery file left behind by a session that didn't exit cleanly — only
+/// when it is newer than the last explicit save, which would otherwise be
+/// the better copy.
+fn load_recovery() -> Option<Recovery> {
+    let modified = |p: PathBuf| std::fs::metadata(p).and_then(|m| m.modified()).ok();
+    let written = modified(recovery_file())?;
+    if modified(saves_file()).is_some_and(|saved| saved >= written) { return None; }
+    serde_json::from_str(&std::fs::read_to_string(recovery_file()).ok()?).ok()
+}
+
+/// Load an asset, recording any failure so it can be shown in the warning banner.
+fn load_or_warn<T: for<'de> serde::Deserialize<'de>>(name: &str, errors: &mut Vec<String>) -> Option<T> {
+    crate::json_loader::load(name).map_err(|e| { eprintln!("Warning: {e}"); errors.push(e); }).ok()
 }
 
 fn load_saves() -> Vec<SavedState> {
-    std::fs::read_to_string(saves_file()).ok()
+    if let Ok(text) = std::fs::read_to_string(saves_file()) {
+        // The header and any line torn by a crash mid-append just fail to parse.
+        return text.lines().filter_map(|l| serde_json::from_str(l).ok()).collect();
+    }
+    let old: Vec<SavedState> = std::fs::read_to_string(legacy_saves_file()).ok()
         .and_then(|s| serde_json::from_str(&s).ok())
-        .unwrap_or_default()
+        .unwrap_or_default();
+    if !old.is_empty() { write_saves(&old); }
+    old
+}
+
+fn save_line(save: &SavedState) -> Option<String> {
+    serde_json::to_string(save).ok().map(|l| l + "\n")
+}
+
+/// Add one save without rewriting the rest of the file.
+fn append_save(save: &SavedState) {
+    use std::io::{Read, Seek, SeekFrom, Write};
+    let Some(line) = save_line(save) else { return };
+    let Ok(mut f) = std::fs::OpenOptions::new().create(true).read(true).append(true).open(saves_file()) else { return };
+    if f.metadata().is_ok_and(|m| m.len() == 0) {
+        let _ = writeln!(f, "{{\"version\":{SAVES_VERSION}}}");
+    } else {
+        // A line torn by a crash has no newline; start this one on a fresh line
+        // so only the torn line is lost on load, not this save with it.
+        let mut last = [0u8];
+        if f.seek(SeekFrom::End(-1)).is_ok() && f.read_exact(&mut last).is_ok() && last[0] != b'\n' {
+            let _ = f.write_all(b"\n");
+        }
+    }
+    let _ = f.write_all(line.as_bytes());
 }
 
+/// Rewrite the whole saves file (deletes, migration).
 fn write_saves(saves: &[SavedState]) {
-    let Ok(json) = serde_json::to_string_pretty(saves) else { return };
+    let mut json = format!("{{\"version\":{SAVES_VERSION}}}\n");
+    json.extend(saves.iter().filter_map(save_line));
     let dest = saves_file();
     // Write to a sibling temp file first, then atomically rename into place.
     // A crash mid-write therefore never corrupts the real saves file.
@@ -219,29 +474,38 @@ fn timestamp() -> String {
     format!("{y}-{mo:02}-{day:02}  {hour:02}:{min:02}")
 }
 
+#[allow(clippy::too_many_arguments)]
 fn load_preset_library(key: &str, path: &str, items: &mut HashMap<String, Arc<Vec<PresetItem>>>,
     meta: &mut HashMap<String, PresetMetadata>, cx: f32, cy: f32,
-    selections: &mut HashMap<String, SelectionState>)
+    selections: &mut HashMap<String, SelectionState>, errors: &mut Vec<String>)
 {
-    let Some(lib) = load_or_warn::<GenericLibrary>(path) else { return };
-    let mut list: Vec<PresetItem> = lib.extract_items().into_iter().map(|gi| {
+    let Some(lib) = load_or_warn::<GenericLibrary>(path, errors) else { return };
+    let mut item_errors = Vec::new();
+    let generic = lib.extract_items(&mut item_errors);
+    errors.extend(item_errors.into_iter().map(|e| format!("{path}: {e}")));
+    let mut list: Vec<PresetItem> = generic.into_iter().map(|gi| {
         let pose_data = gi.to_pose(cx, cy, 40.0);
         PresetItem {
             id: gi.id.clone(), name: if gi.name.is_empty() { gi.id.clone() } else { gi.name },
             pose_data,
             prompt: gi.prompt.or_else(|| gi.semantics.map(|s| s.prompt)),
             allow_custom: false,
+            camera: gi.camera,
+            tags: gi.tags,
+            category: gi.category,
         }
     }).collect();
     if key.contains("style") {
-        if let Some(sl) = load_or_warn::<StylesLibrary>(path) {
+        if let Some(sl) = load_or_warn::<StylesLibrary>(path, errors) {
             list = sl.styles.iter().map(|s| PresetItem {
                 id: s.id.clone(), name: s.name.clone(),
-                pose_data: None, prompt: Some(s.positive.clone()), allow_custom: false,
+                pose_data: None, prompt: Some(s.positive.clone()), allow_custom: false, camera: None,
+                tags: vec![], category: None,
             }).collect();
             list.push(PresetItem {
                 id: "Custom".into(), name: "Custom".into(),
-                pose_data: None, prompt: None, allow_custom: true,
+                pose_data: None, prompt: None, allow_custom: true, camera: None,
+                tags: vec![], category: None,
             });
         }
     }
@@ -259,8 +523,9 @@ fn load_preset_library(key: &str, path: &str, items: &mut HashMap<String, Arc<Ve
 
 impl Default for PromptPuppetApp {
     fn default() -> Self {
+        let mut load_errors = Vec::new();
         let ui_config: crate::json_loader::UiConfig =
-            load_or_warn("ui_config.json").unwrap_or(crate::json_loader::UiConfig { panels: vec![] });
+            load_or_warn("ui_config.json", &mut load_errors).unwrap_or(crate::json_loader::UiConfig { panels: vec![] });
         let (mut libraries, mut options, mut settings_meta, mut settings) =
             (HashMap::new(), HashMap::new(), HashMap::new(), HashMap::new());
 
@@ -268,11 +533,11 @@ impl Default for PromptPuppetApp {
             let key = panel.data_source.trim_end_matches(".json");
             if panel.components.is_empty() {
                 match panel.panel_type.as_str() {
-                    "options_grid" => if let Some(lib) = load_or_warn::<OptionsLibrary>(&panel.data_source) {
+                    "options_grid" => if let Some(lib) = load_or_warn::<OptionsLibrary>(&panel.data_source, &mut load_errors) {
                         options.insert(key.into(), OptionsData::from_library(&lib));
                         libraries.insert(key.into(), lib);
                     },
-                    "controls" => if let Some(lib) = load_or_warn::<SettingsLibrary>(&panel.data_source) {
+                    "controls" => if let Some(lib) = load_or_warn::<SettingsLibrary>(&panel.data_source, &mut load_errors) {
                         settings.insert(key.into(), Settings::from_library(&lib));
                         settings_meta.insert(key.into(), lib);
                     },
@@ -297,64 +562,338 @@ impl Default for PromptPuppetApp {
         for panel in &ui_config.panels {
             let key = panel.data_source.trim_end_matches(".json");
             if panel.panel_type == "preset_selector" {
-                load_preset_library(key, &panel.data_source, &mut preset_items, &mut preset_metadata, CX, CY, &mut selections);
+                load_preset_library(key, &panel.data_source, &mut preset_items, &mut preset_metadata, CX, CY, &mut selections, &mut load_errors);
             }
             for comp in &panel.components {
                 let ckey = comp.data_source.trim_end_matches(".json");
                 if matches!(comp.component_type.as_str(), "dropdown"|"searchable_dropdown") {
-                    load_preset_library(ckey, &comp.data_source, &mut preset_items, &mut preset_metadata, CX, CY, &mut selections);
+                    load_preset_library(ckey, &comp.data_source, &mut preset_items, &mut preset_metadata, CX, CY, &mut selections, &mut load_errors);
                 }
             }
         }
 
-        let dark_mode = std::fs::read_to_string(theme_file()).ok()
+        let (dark_mode, canvas_theme, capsule_bones, show_guides, help_seen) = std::fs::read_to_string(theme_file()).ok()
             .and_then(|s| serde_json::from_str::<ThemePref>(&s).ok())
-            .map(|t| t.dark_mode).unwrap_or(true);
+            .map_or((true, Default::default(), false, false, false),
+                    |t| (t.dark_mode, t.canvas_theme, t.capsule_bones, t.show_guides, t.help_seen));
+
+        let startup: StartupPref = std::fs::read_to_string(startup_file()).ok()
+            .and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default();
+        if let StartupPose::Preset { library, id } = &startup.pose {
+            let known = preset_items.get(library).is_some_and(|l| l.iter().any(|i| &i.id == id && i.pose_data.is_some()));
+            if known { selections.insert(library.clone(), SelectionState { selected: vec![id.clone()], sequence: vec![] }); }
+        }
+
+        // The presets above loaded the skeleton, so its override errors are in too.
+        load_errors.extend(crate::json_loader::take_override_errors());
 
-        let default_pose = selections.iter()
+        let (default_id, default_pose) = selections.iter()
             .find_map(|(k, sel)| {
                 let id = sel.selected.first()?;
-                preset_items.get(k)?.iter().find(|i| &i.id == id)?.pose_data.clone()
+                Some((id.clone(), preset_items.get(k)?.iter().find(|i| &i.id == id)?.pose_data.clone()?))
             })
             .expect("FATAL: No default pose in JSON. Check poses.json has a default with stick_figure data.");
+        let (pose, pose_is_manual, pose_origin) = match (&startup.pose, startup.last_pose) {
+            (StartupPose::LastUsed, Some(p)) => (p, true, None),
+            _ => (default_pose.clone(), false, Some(default_id)),
+        };
 
-        let state = AppState { options, settings, pose: default_pose.clone(),
-            video_mode: false, selections, custom_data: HashMap::new() };
+        let state = AppState { options, settings, pose,
+            video_mode: false, selections, custom_data: HashMap::new(), keyframes: vec![],
+            muted_sections: BTreeSet::new(), pose_origin };
         Self {
             state, libraries, settings_meta, preset_items,
             preset_metadata, default_pose,
             dragging_joint_3d: None,
-            search: HashMap::new(), popup_open: HashMap::new(),
-            generated_prompt: String::new(), status_message: String::new(),
-            status_timer: 0.0, ui_config: Arc::new(ui_config), state_hash: 0, dark_mode,
-            save_dialog: None, load_dialog: false, saves: load_saves(),
+            measure_joints: Vec::new(),
+            search: HashMap::new(), popup_open: HashMap::new(), tag_filter: HashMap::new(),
+            generated_prompt: String::new(), toasts: Vec::new(),
+            activity_log: Vec::new(), show_log: false, ui_config: Arc::new(ui_config), state_hash: 0, dark_mode, canvas_theme, capsule_bones,
+            show_guides,
+            save_dialog: None, load_dialog: false, reset_dialog: false, overwrite_dialog: None, saves: load_saves(),
+            recovery: load_recovery(), autosave_since: None, autosave_hash: 0,
+            show_help: !help_seen, help_seen,
             camera_3d: Camera3D::default(),
-            pose_is_manual: false,
+            pose_is_manual,
+            startup_pose: startup.pose,
             prompt_throttle: 0.0,
+            prompt_dirty: false,
+            timeline_pos: 0.0,
+            load_errors,
+            random_seed: 1,
+            verbose_pose: false,
+            even_weight: false,
+            danbooru_tags: false,
+            prompt_style: Default::default(),
+            boilerplate: std::fs::read_to_string(boilerplate_file()).ok()
+                .and_then(|s| serde_json::from_str(&s).ok()).unwrap_or_default(),
+            preset_delta: false,
+            show_pose_stats: false,
+            show_bone_lengths: false,
+            show_facing: true,
+            hidden_limbs: Default::default(),
+            fk_drag: false,
+            pin_root: false,
+            pose_strength: 1.0, strength_base: None,
+            body_rotation: 0.0, rotation_base: None,
+            preset_stash: None, preset_blend: 1.0,
+            reference_image: None, reference_opacity: 0.35,
+            snapshot_px: 1024,
+            show_sections: false,
+            prompt_sections: Vec::new(),
+            awaiting_paste: 0,
             dance_mode: false, dance_time: 0.0, pre_dance_pose: None,
+            loop_anim: crate::ftlz::LoopAnim::Dance, loop_choice: Default::default(),
         }
     }
 }
 
 impl PromptPuppetApp {
     pub fn new(cc: &eframe::CreationContext<'_>) -> Self {
+        crate::json_loader::enable_user_overrides();
         let mut app = Self::default();
         cc.egui_ctx.set_theme(if app.dark_mode { egui::Theme::Dark } else { egui::Theme::Light });
         app.update_prompt();
+        // The opening state is nothing to recover; only later edits get autosaved.
+        app.state_hash = { let mut h = DefaultHasher::new(); app.state.hash(&mut h); h.finish() };
+        app.autosave_hash = app.state_hash;
         app
     }
+    /// Called just before a preset pose replaces the live one. Keeps the
+    /// existing stash while the live pose is still the previous preset
+    /// untouched, so auditioning several presets reverts to the original work.
+    pub fn stash_before_preset(&mut self, preset: &Pose) {
+        let hash = |p: &Pose| { let mut h = DefaultHasher::new(); p.hash(&mut h); h.finish() };
+        let auditioning = self.preset_stash.as_ref().is_some_and(|s| hash(&s.applied) == hash(&self.state.pose));
+        match &mut self.preset_stash {
+            Some(stash) if auditioning => stash.applied = preset.clone(),
+            _ => self.preset_stash = Some(PresetStash {
+                before: self.state.pose.clone(), origin: self.state.pose_origin.clone(),
+                pose_is_manual: self.pose_is_manual, selections: self.state.selections.clone(),
+                applied: preset.clone(),
+            }),
+        }
+        self.preset_blend = 1.0;
+    }
+    /// Put back the pose (and selections) from before the preset was picked.
+    fn revert_preset(&mut self) {
+        let Some(stash) = self.preset_stash.take() else { return };
+        self.state.pose = stash.before;
+        self.state.pose_origin = stash.origin;
+        self.state.selections = stash.selections;
+        self.pose_is_manual = stash.pose_is_manual;
+        self.preset_blend = 1.0;
+        self.set_status("↩ Preset reverted", 2.0);
+    }
+    /// "🔀 Blend" slider: interpolate joint by joint from the stashed pose to
+    /// the preset. Anything short of the full preset is a manual pose.
+    fn apply_preset_blend(&mut self) {
+        let Some(stash) = &self.preset_stash else { return };
+        let t = self.preset_blend;
+        self.state.pose = stash.before.lerp(&stash.applied, t);
+        self.pose_is_manual = t < 1.0;
+    }
     pub fn reset_pose_to_default(&mut self) {
+        self.preset_stash = None;
         self.state.pose = self.default_pose.clone();
+        self.state.pose_origin = None;
         self.pose_is_manual = false;
         self.set_status("✅ Reset to default pose", 2.0);
     }
+    pub fn randomize_pose(&mut self) {
+        self.preset_stash = None;
+        let cx = self.default_pose.crotch.x;
+        let cy = self.default_pose.left_ankle.y.max(self.default_pose.right_ankle.y);
+        self.state.pose = Pose::random(cx, cy, self.random_seed, crate::skeleton::get());
+        self.state.pose_origin = None;
+        self.pose_is_manual = true;
+        self.set_status(&format!("🎲 Random pose (seed {})", self.random_seed), 2.0);
+        self.random_seed = self.random_seed.wrapping_add(1);
+    }
+    /// Persist the startup choice, plus the live pose when it's "last used".
+    fn write_theme_pref(&self) {
+        let pref = ThemePref { dark_mode: self.dark_mode, canvas_theme: self.canvas_theme,
+                               capsule_bones: self.capsule_bones, show_guides: self.show_guides,
+                               help_seen: self.help_seen };
+        if let Ok(s) = serde_json::to_string(&pref) { let _ = std::fs::write(theme_file(), s); }
+    }
+    fn write_startup_pref(&self) {
+        let last_pose = (self.startup_pose == StartupPose::LastUsed).then(|| match (self.dance_mode, &self.pre_dance_pose) {
+            (true, Some(pre)) => pre.clone(),
+            _ => self.state.pose.clone(),
+        });
+        let pref = StartupPref { pose: self.startup_pose.clone(), last_pose };
+        if let Ok(s) = serde_json::to_string(&pref) { let _ = std::fs::write(startup_file(), s); }
+    }
+    /// The currently selected preset that carries a pose, as a startup choice.
+    fn current_pose_preset(&self) -> Option<(StartupPose, String)> {
+        self.state.selections.iter().find_map(|(lib, sel)| {
+            let id = sel.selected.first()?;
+            let item = self.preset_items.get(lib)?.iter().find(|i| &i.id == id && i.pose_data.is_some())?;
+            Some((StartupPose::Preset { library: lib.clone(), id: id.clone() }, item.name.clone()))
+        })
+    }
+    /// Drive the "💪 Strength" slider: preview `base.scale_from(default, k)` while
+    /// dragging, keep it on release. Back at 1.0 the original pose is restored.
+    fn apply_strength(&mut self, resp: &egui::Response) {
+        if resp.drag_started() || (resp.changed() && self.strength_base.is_none()) {
+            self.strength_base = Some(self.state.pose.clone());
+        }
+        if resp.changed() {
+            if let Some(base) = &self.strength_base {
+                self.state.pose = base.scale_from(&self.default_pose, self.pose_strength, crate::skeleton::get());
+            }
+        }
+        if !resp.dragged() && self.strength_base.is_some() {
+            self.strength_base = None;
+            if (self.pose_strength - 1.0).abs() > 1e-3 {
+                self.pose_is_manual = true;
+                self.set_status(&format!("💪 Pose strength ×{:.2} applied", self.pose_strength), 2.0);
+            }
+            self.pose_strength = 1.0;
+        }
+    }
+    /// Drive the "🔃 Turn" slider: preview `base` turned about the crotch by the
+    /// slider angle while dragging, keep it on release and recentre the slider.
+    fn apply_rotation(&mut self, resp: &egui::Response) {
+        if resp.drag_started() || (resp.changed() && self.rotation_base.is_none()) {
+            self.rotation_base = Some(self.state.pose.clone());
+        }
+        if resp.changed() {
+            if let Some(base) = &self.rotation_base {
+                self.state.pose = base.clone();
+                self.state.pose.rotate_y(self.body_rotation);
+            }
+        }
+        if !resp.dragged() && self.rotation_base.is_some() {
+            self.rotation_base = None;
+            if self.body_rotation.abs() > 0.5 {
+                self.pose_is_manual = true;
+                self.set_status(&format!("🔃 Figure turned {:+.0}°", self.body_rotation), 2.0);
+            }
+            self.body_rotation = 0.0;
+        }
+    }
+    /// Re-anchor an externally produced pose onto this canvas: crotch over the
+    /// default pose's crotch, lower ankle on the default floor. Poses copied
+    /// from another window size or scale otherwise land off-screen.
+    fn normalize_pose(&self, mut pose: Pose) -> Pose {
+        let d = &self.default_pose;
+        let floor = d.left_ankle.y.max(d.right_ankle.y);
+        let dx = d.crotch.x - pose.crotch.x;
+        let dy = floor - pose.left_ankle.y.max(pose.right_ankle.y);
+        let dz = d.crotch.z - pose.crotch.z;
+        pose.translate(dx, dy, dz);
+        pose
+    }
+    /// Apply pose JSON from the clipboard. Accepts a bare `Pose` or a full
+    /// `SavedState` (as written by Save State), in that order.
+    pub fn paste_pose(&mut self, text: &str) {
+        let parsed = serde_json::from_str::<Pose>(text).or_else(|e|
+            serde_json::from_str::<SavedState>(text).map(|s| s.state.pose).map_err(|_| e));
+        #[cfg(feature = "mediapipe")]
+        let parsed = parsed.or_else(|e| crate::json_loader::mediapipe_item(text).ok()
+            .and_then(|gi| gi.to_pose(0.0, 0.0, 40.0)).ok_or(e));
+        match parsed {
+            Ok(pose) => {
+                self.state.pose = self.normalize_pose(pose);
+                self.state.pose_origin = None;
+                self.pose_is_manual = true;
+                self.update_prompt();
+                self.set_status("📋 Pose pasted", 2.0);
+            }
+            Err(e) => self.set_status(&format!("❌ Clipboard is not pose JSON: {e}"), 4.0),
+        }
+    }
+    /// Show `msg` as a toast for `dur` seconds and append it to the activity log.
+    /// Repeating the newest toast just restarts its timer instead of stacking.
     pub fn set_status(&mut self, msg: &str, dur: f32) {
-        self.status_message = msg.to_string(); self.status_timer = dur;
+        match self.toasts.last_mut() {
+            Some((m, t)) if m == msg => *t = dur,
+            _ => self.toasts.push((msg.to_string(), dur)),
+        }
+        if self.toasts.len() > MAX_TOASTS { self.toasts.remove(0); }
+        self.activity_log.push(msg.to_string());
+        if self.activity_log.len() > MAX_LOG { self.activity_log.remove(0); }
+    }
+    /// Scrollable 📜 window of this session's status messages, newest first.
+    fn show_activity_log(&mut self, ctx: &Context) {
+        let mut open = true;
+        let mut clear = false;
+        egui::Window::new("📜 Activity log").open(&mut open).default_size([320.0, 260.0])
+            .show(ctx, |ui| {
+                if self.activity_log.is_empty() {
+                    ui.label(RichText::new("Nothing yet — saves, loads and copies show up here.").weak());
+                }
+                ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+                    for msg in self.activity_log.iter().rev() { ui.label(msg); }
+                });
+                ui.separator();
+                clear = ui.button("🗑 Clear").clicked();
+            });
+        if clear { self.activity_log.clear(); }
+        self.show_log = open;
+    }
+    /// Start playing `anim` from the current pose, snapshotting it for restore.
+    pub fn start_loop(&mut self, anim: crate::ftlz::LoopAnim) {
+        self.pre_dance_pose = Some(self.state.pose.clone());
+        self.dance_mode = true;
+        self.dance_time = 0.0;
+        self.loop_anim  = anim;
+    }
+    /// Leave dance mode and put back the pose it started from.
+    pub fn stop_dance(&mut self) {
+        self.dance_mode = false;
+        self.dance_time = 0.0;
+        if let Some(saved) = self.pre_dance_pose.take() {
+            self.state.pose = saved;
+        }
+        if self.loop_anim == crate::ftlz::LoopAnim::Dance {
+            self.set_status("🛑 Dance mode off", 2.0);
+        } else {
+            self.set_status(&format!("⏹ {} stopped", self.loop_anim.label()), 2.0);
+        }
     }
     pub fn update_prompt(&mut self) {
-        self.generated_prompt = PromptGenerator::new(&self.state, &self.libraries,
+        self.generated_prompt = self.prompt_for(&self.state, self.pose_is_manual);
+        self.prompt_sections = if self.show_sections {
+            self.generator(&self.state, self.pose_is_manual).generate_sections()
+        } else { Vec::new() };
+    }
+    fn describe_options(&self) -> crate::semantics::DescribeOptions {
+        crate::semantics::DescribeOptions {
+            verbose: self.verbose_pose, even_weight: self.even_weight,
+            danbooru: self.danbooru_tags,
+        }
+    }
+    fn generator<'a>(&'a self, state: &'a AppState, pose_is_manual: bool) -> PromptGenerator<'a> {
+        PromptGenerator::new(state, &self.libraries,
             &self.settings_meta, &self.preset_items, &self.preset_metadata,
-            &self.ui_config, self.pose_is_manual).generate();
+            &self.ui_config, pose_is_manual)
+            .describe_options(self.describe_options())
+            .preset_delta(self.preset_delta)
+            .style(self.prompt_style)
+            .boilerplate(&self.boilerplate.prefix, &self.boilerplate.suffix)
+    }
+    /// Generate the prompt for any state with the current libraries and options,
+    /// without touching the live app.
+    fn prompt_for(&self, state: &AppState, pose_is_manual: bool) -> String {
+        self.generator(state, pose_is_manual).generate()
+    }
+    /// Write every saved state's prompt to one text file, `## name (timestamp)`
+    /// per entry. Saves are rendered as `do_load` would show them.
+    fn export_all_prompts(&mut self) {
+        let Some(path) = rfd::FileDialog::new().set_file_name("prompts.txt")
+            .add_filter("Text", &["txt"]).save_file() else { return };
+        let mut out = String::new();
+        for save in &self.saves {
+            let prompt = self.prompt_for(&save.state, false);
+            out.push_str(&format!("## {} ({})\n\n{}\n\n", save.name, save.timestamp, prompt.trim_end()));
+        }
+        match std::fs::write(&path, out) {
+            Ok(()) => self.set_status(&format!("📤 Exported {} prompts", self.saves.len()), 3.0),
+            Err(e) => self.set_status(&format!("❌ Export failed: {e}"), 4.0),
+        }
     }
     fn do_save(&mut self, name: String) {
         // If dancing, save the pre-dance pose — not a frozen mid-animation frame.
@@ -365,19 +904,165 @@ impl PromptPuppetApp {
         } else {
             self.state.clone()
         };
-        self.saves.push(SavedState { name: name.clone(), timestamp: timestamp(), state: save_state });
-        write_saves(&self.saves);
+        let save = SavedState { name: name.clone(), timestamp: timestamp(), state: save_state };
+        // An existing name was confirmed in the overwrite dialog: replace in place.
+        if let Some(i) = self.saves.iter().position(|s| s.name == name) {
+            self.saves[i] = save;
+            write_saves(&self.saves);
+            self.set_status(&format!("✅ Overwrote \"{name}\""), 3.0);
+            return;
+        }
+        append_save(&save);
+        self.saves.push(save);
         self.set_status(&format!("✅ Saved \"{name}\""), 3.0);
     }
     fn do_load(&mut self, idx: usize) {
         if let Some(saved) = self.saves.get(idx) {
             let name = saved.name.clone();
             self.state = saved.state.clone();
+            self.preset_stash = None;
             self.pose_is_manual = false;
             self.update_prompt();
             self.set_status(&format!("✅ Loaded \"{name}\""), 3.0);
         }
     }
+    fn export_bundle(&mut self) {
+        let Some(path) = rfd::FileDialog::new().set_file_name("promptpuppet_bundle.json")
+            .add_filter("JSON", &["json"]).save_file() else { return };
+        let mut state = self.state.clone();
+        if let (true, Some(pre)) = (self.dance_mode, &self.pre_dance_pose) { state.pose = pre.clone(); }
+        let bundle = Bundle { version: Bundle::VERSION, prompt: self.prompt_for(&state, self.pose_is_manual),
+                              pose_is_manual: self.pose_is_manual, state };
+        let res = serde_json::to_string_pretty(&bundle).map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+        match res {
+            Ok(()) => self.set_status("📦 Bundle exported", 2.0),
+            Err(e) => self.set_status(&format!("❌ Export failed: {e}"), 4.0),
+        }
+    }
+    fn export_settings(&mut self) {
+        let Some(path) = rfd::FileDialog::new().set_file_name("promptpuppet_settings.json")
+            .add_filter("JSON", &["json"]).save_file() else { return };
+        let file = SettingsFile { version: SettingsFile::VERSION,
+                                  options: self.state.options.clone(), settings: self.state.settings.clone() };
+        let res = serde_json::to_string_pretty(&file).map_err(|e| e.to_string())
+            .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+        match res {
+            Ok(()) => self.set_status("⚙ Settings exported", 2.0),
+            Err(e) => self.set_status(&format!("❌ Export failed: {e}"), 4.0),
+        }
+    }
+    /// Merge a settings file into the current state: every value it carries
+    /// overwrites the current one, everything else (pose included) is kept.
+    fn import_settings(&mut self) {
+        let Some(path) = rfd::FileDialog::new().add_filter("JSON", &["json"]).pick_file() else { return };
+        let res = std::fs::read_to_string(&path).map_err(|e| e.to_string())
+            .and_then(|s| serde_json::from_str::<SettingsFile>(&s).map_err(|e| e.to_string()));
+        match res {
+            Ok(f) if f.version > SettingsFile::VERSION => self.set_status(
+                &format!("❌ Settings version {} is newer than this app supports ({})", f.version, SettingsFile::VERSION), 4.0),
+            Ok(f) => {
+                let mut n = 0;
+                for (id, data) in f.options {
+                    n += data.values.len();
+                    self.state.options.entry(id).or_default().values.extend(data.values);
+                }
+                for (id, data) in f.settings {
+                    n += data.values.len();
+                    self.state.settings.entry(id).or_default().values.extend(data.values);
+                }
+                self.update_prompt();
+                self.set_status(&format!("⚙ Imported {n} settings"), 2.0);
+            }
+            Err(e) => self.set_status(&format!("❌ Import failed: {e}"), 4.0),
+        }
+    }
+    /// Save the figure as a square PNG, seen from the current canvas angle.
+    fn export_image(&mut self) {
+        let Some(path) = rfd::FileDialog::new().set_file_name("pose.png")
+            .add_filter("PNG", &["png"]).save_file() else { return };
+        let pal = crate::canvas3d::Palette {
+            capsules: self.capsule_bones, hidden: self.hidden_limbs.clone(),
+            ..self.canvas_theme.palette(self.dark_mode)
+        };
+        let pose = match (self.dance_mode, &self.pre_dance_pose) {
+            (true, Some(pre)) => pre,
+            _ => &self.state.pose,
+        };
+        let img = crate::canvas3d::render_image(pose, &self.camera_3d, self.snapshot_px, &pal);
+        match img.save_with_format(&path, image::ImageFormat::Png) {
+            Ok(()) => self.set_status("🖼 Image exported", 2.0),
+            Err(e) => self.set_status(&format!("❌ Export failed: {e}"), 4.0),
+        }
+    }
+    fn import_bundle(&mut self) {
+        let Some(path) = rfd::FileDialog::new().add_filter("JSON", &["json"]).pick_file() else { return };
+        let res = std::fs::read_to_string(&path).map_err(|e| e.to_string())
+            .and_then(|s| serde_json::from_str::<Bundle>(&s).map_err(|e| e.to_string()));
+        match res {
+            Ok(b) if b.version > Bundle::VERSION => self.set_status(
+                &format!("❌ Bundle version {} is newer than this app supports ({})", b.version, Bundle::VERSION), 4.0),
+            Ok(b) => {
+                self.state = b.state;
+                self.pose_is_manual = b.pose_is_manual;
+                self.update_prompt();
+                self.set_status("📥 Bundle imported", 2.0);
+            }
+            Err(e) => self.set_status(&format!("❌ Import failed: {e}"), 4.0),
+        }
+    }
+    /// Load a `joint,x,y,z` spreadsheet export as the current pose.
+    fn import_csv(&mut self) {
+        let Some(path) = rfd::FileDialog::new().add_filter("CSV", &["csv", "txt"]).pick_file() else { return };
+        let res = std::fs::read_to_string(&path).map_err(|e| e.to_string()).and_then(|s|
+            crate::json_loader::pose_from_csv(&s, 0.0, 0.0, 40.0).ok_or_else(|| "no joint rows found".to_string()));
+        match res {
+            Ok(pose) => {
+                self.state.pose = self.normalize_pose(pose);
+                self.state.pose_origin = None;
+                self.pose_is_manual = true;
+                self.update_prompt();
+                self.set_status("📄 CSV pose imported", 2.0);
+            }
+            Err(e) => self.set_status(&format!("❌ CSV import failed: {e}"), 4.0),
+        }
+    }
+    /// Pick a photo to trace the pose from; it is drawn scaled-to-fit behind
+    /// the canvas until removed.
+    fn load_reference_image(&mut self, ctx: &Context) {
+        let Some(path) = rfd::FileDialog::new()
+            .add_filter("Image", &["png", "jpg", "jpeg", "webp", "bmp"]).pick_file() else { return };
+        match image::open(&path) {
+            Ok(img) => {
+                let rgba = img.to_rgba8();
+                let size = [rgba.width() as usize, rgba.height() as usize];
+                let color = egui::ColorImage::from_rgba_unmultiplied(size, rgba.as_raw());
+                self.reference_image = Some(ctx.load_texture("reference_image", color, egui::TextureOptions::LINEAR));
+                self.set_status("🖼 Reference image loaded", 2.0);
+            }
+            Err(e) => self.set_status(&format!("❌ Couldn't open image: {e}"), 4.0),
+        }
+    }
+    /// Throttled crash-recovery write: at most every `AUTOSAVE_SECS`, and only
+    /// when the state changed since the last write. Waits until any pending
+    /// restore has been answered so it can't overwrite the file being offered.
+    fn autosave(&mut self, ctx: &Context) {
+        if self.state_hash == self.autosave_hash || self.recovery.is_some() { return; }
+        let since = *self.autosave_since.get_or_insert_with(std::time::Instant::now);
+        let wait = std::time::Duration::from_secs_f32(AUTOSAVE_SECS).saturating_sub(since.elapsed());
+        if !wait.is_zero() {
+            // An idle window doesn't repaint; wake up for the pending write.
+            ctx.request_repaint_after(wait);
+            return;
+        }
+        self.autosave_since = None;
+        self.autosave_hash = self.state_hash;
+        let mut state = self.state.clone();
+        if let (true, Some(pre)) = (self.dance_mode, &self.pre_dance_pose) { state.pose = pre.clone(); }
+        let Ok(json) = serde_json::to_string(&Recovery { pose_is_manual: self.pose_is_manual, state }) else { return };
+        let (dest, tmp) = (recovery_file(), recovery_file().with_extension("tmp"));
+        if std::fs::write(&tmp, json).is_ok() { let _ = std::fs::rename(&tmp, &dest); }
+    }
     fn do_delete(&mut self, idx: usize) {
         if idx < self.saves.len() {
             let name = self.saves.remove(idx).name;
@@ -417,7 +1102,7 @@ fn ghost_btn(ui: &mut egui::Ui, label: &str) -> egui::Response {
         .fill(egui::Color32::TRANSPARENT).corner_radius(egui::CornerRadius::same(6)))
 }
 
-enum DialogAction { Save(String), Load(usize), Delete(usize), Cancel }
+enum DialogAction { Save(String), Load(usize), Delete(usize), ExportAll, Reset, Restore, Cancel }
 
 fn show_save_dialog(ctx: &Context, dark: bool, buf: &mut String) -> Option<DialogAction> {
     let mut action = None;
@@ -444,6 +1129,121 @@ fn show_save_dialog(ctx: &Context, dark: bool, buf: &mut String) -> Option<Dialo
     action
 }
 
+fn show_reset_dialog(ctx: &Context, dark: bool) -> Option<DialogAction> {
+    let mut action = None;
+    let muted = if dark { egui::Color32::from_gray(160) } else { egui::Color32::from_gray(90) };
+    egui::Window::new("🔄  Reset Pose").collapsible(false).resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0,0.0]).frame(dialog_frame(dark))
+        .show(ctx, |ui| {
+            ui.set_min_width(300.0);
+            ui.label(RichText::new("Reset pose? Unsaved changes will be lost.").color(muted).size(13.0));
+            ui.add_space(14.0);
+            ui.horizontal(|ui| {
+                if accent_btn(ui, "  Reset  ").clicked() || ui.input(|i| i.key_pressed(Key::Enter)) {
+                    action = Some(DialogAction::Reset);
+                }
+                ui.add_space(8.0);
+                if ghost_btn(ui, "Cancel").clicked() { action = Some(DialogAction::Cancel); }
+            });
+            if ui.input(|i| i.key_pressed(Key::Escape)) { action = Some(DialogAction::Cancel); }
+        });
+    action
+}
+
+fn show_overwrite_dialog(ctx: &Context, dark: bool, name: &str) -> Option<DialogAction> {
+    let mut action = None;
+    let muted = if dark { egui::Color32::from_gray(160) } else { egui::Color32::from_gray(90) };
+    egui::Window::new("💾  Overwrite Save").collapsible(false).resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0,0.0]).frame(dialog_frame(dark))
+        .show(ctx, |ui| {
+            ui.set_min_width(300.0);
+            ui.label(RichText::new(format!("Overwrite existing save \"{name}\"?")).color(muted).size(13.0));
+            ui.add_space(14.0);
+            ui.horizontal(|ui| {
+                if accent_btn(ui, "  Overwrite  ").clicked() || ui.input(|i| i.key_pressed(Key::Enter)) {
+                    action = Some(DialogAction::Save(name.to_string()));
+                }
+                ui.add_space(8.0);
+                if ghost_btn(ui, "Cancel").clicked() { action = Some(DialogAction::Cancel); }
+            });
+            if ui.input(|i| i.key_pressed(Key::Escape)) { action = Some(DialogAction::Cancel); }
+        });
+    action
+}
+
+fn show_recovery_dialog(ctx: &Context, dark: bool) -> Option<DialogAction> {
+    let mut action = None;
+    let muted = if dark { egui::Color32::from_gray(160) } else { egui::Color32::from_gray(90) };
+    egui::Window::new("🩹  Restore Session").collapsible(false).resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0,0.0]).frame(dialog_frame(dark))
+        .show(ctx, |ui| {
+            ui.set_min_width(300.0);
+            ui.label(RichText::new("PromptPuppet didn't close cleanly last time. Restore the unsaved pose and selections?")
+                .color(muted).size(13.0));
+            ui.add_space(14.0);
+            ui.horizontal(|ui| {
+                if accent_btn(ui, "  Restore  ").clicked() || ui.input(|i| i.key_pressed(Key::Enter)) {
+                    action = Some(DialogAction::Restore);
+                }
+                ui.add_space(8.0);
+                if ghost_btn(ui, "Discard").clicked() { action = Some(DialogAction::Cancel); }
+            });
+            if ui.input(|i| i.key_pressed(Key::Escape)) { action = Some(DialogAction::Cancel); }
+        });
+    action
+}
+
+/// Canvas controls, shortcuts and the prompt workflow. Returns true when dismissed.
+fn show_help_overlay(ctx: &Context, dark: bool) -> bool {
+    let mut close = false;
+    let (pri, sec) = if dark { (egui::Color32::WHITE, egui::Color32::from_gray(150)) }
+                     else    { (egui::Color32::from_gray(20), egui::Color32::from_gray(90)) };
+    let section = |ui: &mut egui::Ui, title: &str, rows: &[(&str, &str)]| {
+        ui.label(RichText::new(title).strong().size(14.0).color(pri));
+        ui.add_space(4.0);
+        egui::Grid::new(title).num_columns(2).spacing([16.0, 4.0]).show(ui, |ui| {
+            for (k, v) in rows {
+                ui.label(RichText::new(*k).monospace().size(12.0).color(pri));
+                ui.label(RichText::new(*v).size(12.0).color(sec));
+                ui.end_row();
+            }
+        });
+        ui.add_space(12.0);
+    };
+    egui::Window::new("❓  How to use PromptPuppet").collapsible(false).resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0,0.0]).frame(dialog_frame(dark))
+        .show(ctx, |ui| {
+            ui.set_min_width(420.0);
+            section(ui, "Canvas", &[
+                ("Drag a joint",      "Pose it — bones keep their length"),
+                ("🦾 FK",             "Drag swings a bone about its parent instead"),
+                ("📌 Pin pelvis",     "Neck/waist drags bend the spine, feet stay put"),
+                ("👁 Limbs",          "Hide arms, legs or head to see what they cover"),
+                ("Drag empty space",  "Orbit the camera"),
+                ("Shift+click",       "Pick two joints to measure length and angle"),
+                ("Scroll",            "Zoom"),
+                ("Front/Back/Left/Right", "Snap to a view"),
+            ]);
+            section(ui, "Shortcuts", &[
+                ("F1",           "Show or hide this help"),
+                ("Ctrl+V",       "Paste pose JSON onto the canvas"),
+                ("Ctrl+Shift+D", "Dance mode (any key or click stops it)"),
+                ("Enter / Esc",  "Confirm / cancel dialogs"),
+            ]);
+            section(ui, "Workflow", &[
+                ("1. Pick",  "Choose a pose preset, style, character and scene on the left"),
+                ("2. Pose",  "Adjust joints — the pose text switches to a live description"),
+                ("3. Copy",  "Copy the generated prompt from the bottom panel"),
+                ("🎬 Video", "Add keyframes and motion settings for video prompts"),
+            ]);
+            ui.horizontal(|ui| {
+                if accent_btn(ui, "  Got it  ").clicked() { close = true; }
+            });
+            if ui.input(|i| i.key_pressed(Key::Escape)) { close = true; }
+        });
+    close
+}
+
 fn show_load_dialog(ctx: &Context, dark: bool, saves: &[SavedState]) -> Option<DialogAction> {
     let mut action = None;
     let (pri, sec) = if dark { (egui::Color32::WHITE, egui::Color32::from_gray(140)) }
@@ -482,12 +1282,35 @@ fn show_load_dialog(ctx: &Context, dark: bool, saves: &[SavedState]) -> Option<D
                 });
             }
             ui.add_space(8.0);
-            if ghost_btn(ui, "Close").clicked() { action = Some(DialogAction::Cancel); }
+            ui.horizontal(|ui| {
+                if ghost_btn(ui, "Close").clicked() { action = Some(DialogAction::Cancel); }
+                if !saves.is_empty() && ui.button("📤 Export All Prompts").clicked() {
+                    action = Some(DialogAction::ExportAll);
+                }
+            });
             if ui.input(|i| i.key_pressed(Key::Escape)) { action = Some(DialogAction::Cancel); }
         });
     action
 }
 
+/// Semantics debug overlay: `semantics::pose_stats` rows in the canvas's lower-left
+/// corner, followed by what has changed since `base` (the default pose).
+fn draw_pose_stats(ui: &egui::Ui, pose: &Pose, base: &Pose, rect: egui::Rect) {
+    let diff = pose.diff_summary(base);
+    let changed = if diff.is_empty() { "nothing".to_string() } else { diff.join("\n                ") };
+    let text = crate::semantics::pose_stats(pose).into_iter()
+        .chain(std::iter::once(("changed", changed)))
+        .map(|(k, v)| format!("{k:<15} {v}")).collect::<Vec<_>>().join("\n");
+    let font = egui::FontId::monospace(11.0);
+    let ink = egui::Color32::from_gray(230);
+    let galley = ui.painter().layout_no_wrap(text, font, ink);
+    let pad = egui::vec2(8.0, 6.0);
+    let min = egui::pos2(rect.min.x + 10.0, rect.max.y - galley.size().y - pad.y * 2.0 - 10.0);
+    let bg = egui::Rect::from_min_size(min, galley.size() + pad * 2.0);
+    ui.painter().rect_filled(bg, 4.0, egui::Color32::from_black_alpha(140));
+    ui.painter().galley(min + pad, galley, ink);
+}
+
 // ── Window chrome ─────────────────────────────────────────────────────────────
 
 fn render_custom_title_bar(ctx: &Context, dark_mode: bool) {
@@ -513,6 +1336,30 @@ fn render_custom_title_bar(ctx: &Context, dark_mode: bool) {
     });
 }
 
+/// Persistent warning strip listing asset load failures. Returns true when dismissed.
+fn render_load_error_banner(ctx: &Context, errors: &[String]) -> bool {
+    let mut dismissed = false;
+    TopBottomPanel::top("load_errors").frame(egui::Frame {
+        inner_margin: egui::Margin::symmetric(10, 6),
+        fill: egui::Color32::from_rgb(90, 40, 20),
+        ..Default::default()
+    }).show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("⚠ {} asset problem(s) — some libraries may be incomplete", errors.len()))
+                .strong().color(egui::Color32::from_rgb(255, 210, 150)));
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.button("✖ Dismiss").clicked() { dismissed = true; }
+            });
+        });
+        ScrollArea::vertical().max_height(90.0).show(ui, |ui| {
+            for e in errors {
+                ui.label(RichText::new(e).monospace().size(11.0).color(egui::Color32::from_rgb(255, 230, 200)));
+            }
+        });
+    });
+    dismissed
+}
+
 fn handle_window_resize(ctx: &Context) {
     use egui::viewport::ResizeDirection as RD;
     let (m, r) = (8.0, ctx.input(|i| i.viewport_rect()));
@@ -540,10 +1387,33 @@ fn handle_window_resize(ctx: &Context) {
 // ── Main loop ─────────────────────────────────────────────────────────────────
 
 impl eframe::App for PromptPuppetApp {
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        if self.startup_pose == StartupPose::LastUsed { self.write_startup_pref(); }
+        let _ = std::fs::remove_file(recovery_file());
+    }
+
     fn update(&mut self, ctx: &Context, _frame: &mut eframe::Frame) {
+        // Before the save dialog, so the Enter that submitted a taken name
+        // doesn't also confirm the overwrite in the same frame.
+        if let Some(name) = self.overwrite_dialog.clone() {
+            match show_overwrite_dialog(ctx, self.dark_mode, &name) {
+                Some(DialogAction::Save(name)) => { self.do_save(name); self.overwrite_dialog = None; }
+                // Back to the save dialog so another name can be typed. An
+                // Escape that cancelled here is eaten, or the save dialog
+                // below would see it too and close straight away.
+                Some(_) => {
+                    ctx.input_mut(|i| i.consume_key(egui::Modifiers::NONE, Key::Escape));
+                    self.save_dialog = Some(name); self.overwrite_dialog = None;
+                }
+                None    => {}
+            }
+        }
         if self.save_dialog.is_some() {
             let mut buf = self.save_dialog.take().unwrap();
             match show_save_dialog(ctx, self.dark_mode, &mut buf) {
+                Some(DialogAction::Save(name)) if self.saves.iter().any(|s| s.name == name) => {
+                    self.overwrite_dialog = Some(name);
+                }
                 Some(DialogAction::Save(name)) => self.do_save(name),
                 Some(_) => {}
                 None    => self.save_dialog = Some(buf),
@@ -555,13 +1425,41 @@ impl eframe::App for PromptPuppetApp {
                 match action {
                     DialogAction::Load(i)   => { self.do_load(i);   self.load_dialog = false; }
                     DialogAction::Delete(i) => self.do_delete(i),
+                    DialogAction::ExportAll => self.export_all_prompts(),
                     DialogAction::Cancel    => self.load_dialog = false,
-                    DialogAction::Save(_)   => {}
+                    DialogAction::Save(_) | DialogAction::Reset | DialogAction::Restore => {}
                 }
             }
         }
+        if self.reset_dialog {
+            match show_reset_dialog(ctx, self.dark_mode) {
+                Some(DialogAction::Reset) => { self.reset_pose_to_default(); self.reset_dialog = false; }
+                Some(_) => self.reset_dialog = false,
+                None    => {}
+            }
+        }
+        if let Some(rec) = &self.recovery {
+            match show_recovery_dialog(ctx, self.dark_mode) {
+                Some(DialogAction::Restore) => {
+                    (self.state, self.pose_is_manual) = (rec.state.clone(), rec.pose_is_manual);
+                    self.recovery = None;
+                    self.update_prompt();
+                    self.set_status("🩹 Session restored", 2.0);
+                }
+                Some(_) => self.recovery = None,
+                None    => {}
+            }
+        }
+        if self.show_log { self.show_activity_log(ctx); }
+        if ctx.input(|i| i.key_pressed(Key::F1)) { self.show_help = !self.show_help; }
+        if self.show_help && show_help_overlay(ctx, self.dark_mode) { self.show_help = false; }
+        // However it was closed (button, Esc, F1, ❓), don't auto-open it again.
+        if !self.show_help && !self.help_seen { self.help_seen = true; self.write_theme_pref(); }
 
         render_custom_title_bar(ctx, self.dark_mode);
+        if !self.load_errors.is_empty() && render_load_error_banner(ctx, &self.load_errors) {
+            self.load_errors.clear();
+        }
 
         TopBottomPanel::top("top_bar").show(ctx, |ui| {
             ui.add_space(4.0);
@@ -571,21 +1469,139 @@ impl eframe::App for PromptPuppetApp {
                     ui.spacing_mut().item_spacing.x = 8.0;
                     if ui.button("💾 Save State").clicked() { self.save_dialog = Some(String::new()); }
                     if ui.button("📂 Load State").clicked() { self.load_dialog = true; }
-                    if ui.button("🔄 Reset Pose").clicked() { self.reset_pose_to_default(); }
+                    if ui.button("📦 Export Bundle").on_hover_text("Prompt + full state as a portable JSON file").clicked() {
+                        self.export_bundle();
+                    }
+                    if ui.button("📥 Import Bundle").clicked() { self.import_bundle(); }
+                    ui.menu_button("⚙ Settings", |ui| {
+                        if ui.button("📤 Export Settings").on_hover_text("Options and settings only, no pose — a shareable style baseline").clicked() {
+                            self.export_settings();
+                            ui.close();
+                        }
+                        if ui.button("📥 Import Settings").on_hover_text("Merge a settings file into the current state, keeping the pose").clicked() {
+                            self.import_settings();
+                            ui.close();
+                        }
+                    });
+                    if ui.button("📄 Import CSV").on_hover_text("Pose from rows of joint,x,y,z (poses.json units, y up)").clicked() {
+                        self.import_csv();
+                    }
+                    if ui.button("🖼 Export Image").on_hover_text("Save the stick figure as a PNG").clicked() {
+                        self.export_image();
+                    }
+                    ui.add(egui::DragValue::new(&mut self.snapshot_px).range(256..=4096).suffix(" px"))
+                        .on_hover_text("Exported image size (square)");
+                    if ui.button("🖼 Reference").on_hover_text("Show a photo behind the figure to trace a pose from").clicked() {
+                        self.load_reference_image(ctx);
+                    }
+                    if self.reference_image.is_some() {
+                        ui.add(egui::Slider::new(&mut self.reference_opacity, 0.0..=1.0).text("opacity"));
+                        if ui.small_button("✖").on_hover_text("Remove the reference image").clicked() {
+                            self.reference_image = None;
+                        }
+                    }
+                    if ui.button("🔄 Reset Pose").clicked() {
+                        // Nothing to lose when the pose is already the default — skip the prompt.
+                        let hash = |p: &Pose| { let mut h = DefaultHasher::new(); p.hash(&mut h); h.finish() };
+                        if hash(&self.state.pose) == hash(&self.default_pose) { self.reset_pose_to_default(); }
+                        else { self.reset_dialog = true; }
+                    }
+                    if ui.button("🎲 Random Pose").clicked() { self.randomize_pose(); }
+                    if self.preset_stash.is_some() {
+                        if ui.button("↩ Revert Preset").on_hover_text("Go back to the pose you had before picking a preset").clicked() {
+                            self.revert_preset();
+                        }
+                        if ui.add(egui::Slider::new(&mut self.preset_blend, 0.0..=1.0).text("🔀 Blend"))
+                            .on_hover_text("Mix the previous pose (0) with the preset (1)").changed() {
+                            self.apply_preset_blend();
+                        }
+                    }
+                    ui.add(egui::DragValue::new(&mut self.random_seed).prefix("seed "))
+                        .on_hover_text("Same seed → same pose");
+                    let resp = ui.add(egui::Slider::new(&mut self.pose_strength, 0.0..=2.0).text("💪 Strength"))
+                        .on_hover_text("Tone the pose down toward neutral (<1) or exaggerate it (>1); applies on release");
+                    self.apply_strength(&resp);
+                    let resp = ui.add(egui::Slider::new(&mut self.body_rotation, -180.0..=180.0).suffix("°").text("🔃 Turn"))
+                        .on_hover_text("Turn the whole figure about its vertical axis; applies on release");
+                    self.apply_rotation(&resp);
+                    if ui.button("📋 Paste Pose").on_hover_text("Apply pose JSON from the clipboard (or Ctrl+V on the canvas)").clicked() {
+                        self.awaiting_paste = 2;
+                        ctx.send_viewport_cmd(egui::ViewportCommand::RequestPaste);
+                    }
                 }); });
                 ui.add_space(12.0);
                 if ui.checkbox(&mut self.state.video_mode, "🎬 Video Mode").changed() {
                     self.clear_invalid_multiselections();
                 }
+                ui.checkbox(&mut self.show_pose_stats, "🐞 Stats")
+                    .on_hover_text("Show the body metrics the pose description is based on");
+                ui.checkbox(&mut self.show_bone_lengths, "📏 Lengths")
+                    .on_hover_text("Label each bone with its length as a fraction of body height");
+                ui.checkbox(&mut self.show_facing, "🧭 Facing")
+                    .on_hover_text("Arrow for where the head looks, chevron for where the chest faces");
+                let limbs_label = if self.hidden_limbs.is_empty() { "👁 Limbs".to_string() }
+                    else { format!("👁 Limbs ({} hidden)", self.hidden_limbs.len()) };
+                ui.menu_button(limbs_label, |ui| {
+                    for limb in crate::canvas3d::Limb::ALL {
+                        let mut shown = !self.hidden_limbs.contains(&limb);
+                        if ui.checkbox(&mut shown, limb.label()).changed() {
+                            if shown { self.hidden_limbs.remove(&limb); } else { self.hidden_limbs.insert(limb); }
+                        }
+                    }
+                    ui.separator();
+                    if ui.add_enabled(!self.hidden_limbs.is_empty(), egui::Button::new("Show all")).clicked() {
+                        self.hidden_limbs.clear();
+                    }
+                }).response.on_hover_text("Hide limbs on the canvas to see what they cover; hidden joints can't be dragged");
+                ui.checkbox(&mut self.fk_drag, "🦾 FK")
+                    .on_hover_text("Dragging a limb joint swings its bone about the parent joint and keeps the bend below it");
+                ui.checkbox(&mut self.pin_root, "📌 Pin pelvis")
+                    .on_hover_text("Neck and waist drags bend the spine and keep the pelvis and feet in place");
                 ui.add_space(12.0);
                 ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                     ui.add_space(8.0);
+                    let mut theme_changed = false;
+                    if ui.button("❓").on_hover_text("Controls and shortcuts (F1)").clicked() {
+                        self.show_help = !self.show_help;
+                    }
+                    if ui.button("📜").on_hover_text("Activity log: recent status messages").clicked() {
+                        self.show_log = !self.show_log;
+                    }
                     if ui.button(if self.dark_mode { "☀ Light" } else { "🌙 Dark" }).clicked() {
                         self.dark_mode = !self.dark_mode;
                         ctx.set_theme(if self.dark_mode { egui::Theme::Dark } else { egui::Theme::Light });
-                        let _ = std::fs::write(theme_file(),
-                            serde_json::json!({"dark_mode": self.dark_mode}).to_string());
+                        theme_changed = true;
                     }
+                    egui::ComboBox::from_id_salt("canvas_theme")
+                        .selected_text(format!("🎨 {}", self.canvas_theme.label()))
+                        .show_ui(ui, |ui| {
+                            for t in crate::canvas3d::CanvasTheme::ALL {
+                                theme_changed |= ui.selectable_value(&mut self.canvas_theme, t, t.label()).changed();
+                            }
+                        });
+                    theme_changed |= ui.checkbox(&mut self.capsule_bones, "🦴 Thick bones")
+                        .on_hover_text("Round, zoom-scaled bones — easier to read where limbs cross")
+                        .changed();
+                    theme_changed |= ui.checkbox(&mut self.show_guides, "📐 Guides")
+                        .on_hover_text("Rule-of-thirds lines, centre crosshair and a ground line at the feet")
+                        .changed();
+                    let label = match &self.startup_pose {
+                        StartupPose::LibraryDefault => "Library default".to_string(),
+                        StartupPose::Preset { id, .. } => id.clone(),
+                        StartupPose::LastUsed => "Last used".to_string(),
+                    };
+                    let mut startup_changed = false;
+                    egui::ComboBox::from_id_salt("startup_pose")
+                        .selected_text(format!("🚀 {label}"))
+                        .show_ui(ui, |ui| {
+                            startup_changed |= ui.selectable_value(&mut self.startup_pose, StartupPose::LibraryDefault, "Library default").changed();
+                            startup_changed |= ui.selectable_value(&mut self.startup_pose, StartupPose::LastUsed, "Last used pose").changed();
+                            if let Some((choice, name)) = self.current_pose_preset() {
+                                startup_changed |= ui.selectable_value(&mut self.startup_pose, choice, format!("Current preset: {name}")).changed();
+                            }
+                        }).response.on_hover_text("Pose to open with next time");
+                    if startup_changed { self.write_startup_pref(); }
+                    if theme_changed { self.write_theme_pref(); }
                 });
             });
             ui.add_space(4.0);
@@ -611,12 +1627,95 @@ impl eframe::App for PromptPuppetApp {
                         ctx.copy_text(self.generated_prompt.clone());
                         self.set_status("✅ Copied to clipboard", 2.0);
                     }
+                    // The live semantic description alone, with the current describe
+                    // options, for tuning the pose apart from the other sections.
+                    // Described lazily — only while hovered or on click.
+                    let pose_desc = || crate::semantics::describe(&self.state.pose, &self.describe_options(),
+                                                                  crate::semantics::config());
+                    let copy_pose = ui.add_sized([110.0,28.0], egui::Button::new(RichText::new("🦴 Copy Pose").size(14.0)))
+                        .on_hover_ui(|ui| { ui.label(pose_desc()); });
+                    if copy_pose.clicked() {
+                        ctx.copy_text(pose_desc());
+                        self.set_status("✅ Pose description copied", 2.0);
+                    }
+                    // Rough size check before pasting into a length-capped model;
+                    // ~4 characters per token is the usual English estimate.
+                    let text = self.generated_prompt.trim();
+                    let words = text.split(|c: char| c.is_whitespace() || c == ',').filter(|w| !w.is_empty()).count();
+                    let chars = text.chars().count();
+                    ui.label(RichText::new(format!("{words} words · {chars} chars · ~{} tokens", chars.div_ceil(4)))
+                        .size(11.0).weak())
+                        .on_hover_text("Token count is an estimate (characters ÷ 4)");
+                    let warnings = self.state.pose.plausibility_warnings();
+                    if !warnings.is_empty() {
+                        ui.label(RichText::new("⚠ unusual pose").size(11.0).color(egui::Color32::from_rgb(230, 170, 60)))
+                            .on_hover_text(format!("May not render well:\n• {}", warnings.join("\n• ")));
+                    }
+                    if ui.checkbox(&mut self.verbose_pose, "🔬 Verbose")
+                        .on_hover_text("Add approximate angles to manual pose descriptions")
+                        .changed() { self.update_prompt(); }
+                    if ui.checkbox(&mut self.even_weight, "⚖ Even weight")
+                        .on_hover_text("Say \"weight evenly distributed\" when both feet are planted and centred")
+                        .changed() { self.update_prompt(); }
+                    if ui.checkbox(&mut self.danbooru_tags, "🏷 Danbooru tags")
+                        .on_hover_text("Describe manual poses as Danbooru tags (standing, arms_up) for anime models")
+                        .changed() { self.update_prompt(); }
+                    let style = self.prompt_style;
+                    egui::ComboBox::from_id_salt("prompt_style")
+                        .selected_text(format!("✒ {}", style.label()))
+                        .show_ui(ui, |ui| {
+                            for s in crate::prompt::PromptStyle::ALL {
+                                ui.selectable_value(&mut self.prompt_style, s, s.label());
+                            }
+                        })
+                        .response.on_hover_text("Mixed: per-panel lists · Tags: one comma tag list · Sentences: flowing prose");
+                    if self.prompt_style != style { self.update_prompt(); }
+                    let active = !self.boilerplate.prefix.trim().is_empty() || !self.boilerplate.suffix.trim().is_empty();
+                    ui.menu_button(if active { "📎 Boilerplate ✔" } else { "📎 Boilerplate" }, |ui| {
+                        let mut changed = false;
+                        ui.label("Before the prompt");
+                        changed |= ui.add(egui::TextEdit::singleline(&mut self.boilerplate.prefix)
+                            .hint_text("masterpiece, best quality,").desired_width(280.0)).changed();
+                        ui.label("After the prompt");
+                        changed |= ui.add(egui::TextEdit::singleline(&mut self.boilerplate.suffix)
+                            .hint_text("model-specific tags").desired_width(280.0)).changed();
+                        if changed {
+                            if let Ok(s) = serde_json::to_string(&self.boilerplate) {
+                                let _ = std::fs::write(boilerplate_file(), s);
+                            }
+                            self.update_prompt();
+                        }
+                    }).response.on_hover_text("Fixed text added before and after every generated prompt");
+                    if ui.checkbox(&mut self.preset_delta, "Δ Preset + changes")
+                        .on_hover_text("For an edited preset pose, keep the preset's wording and add what changed")
+                        .changed() { self.update_prompt(); }
+                    if ui.checkbox(&mut self.show_sections, "🧩 Sections")
+                        .on_hover_text("Show the prompt split by panel, with a copy button per section")
+                        .changed() { self.update_prompt(); }
                 });
             });
             ui.add_space(4.0); ui.separator(); ui.add_space(2.0);
             ScrollArea::vertical().show(ui, |ui| {
-                ui.add(egui::TextEdit::multiline(&mut self.generated_prompt.as_str())
-                    .desired_width(f32::INFINITY).font(egui::TextStyle::Monospace).interactive(false));
+                if !self.show_sections {
+                    ui.add(egui::TextEdit::multiline(&mut self.generated_prompt.as_str())
+                        .desired_width(f32::INFINITY).font(egui::TextStyle::Monospace).interactive(false));
+                    return;
+                }
+                let mut copied = None;
+                for (label, text) in &self.prompt_sections {
+                    ui.horizontal(|ui| {
+                        if ui.small_button("📋").on_hover_text("Copy this section").clicked() {
+                            copied = Some((label.clone(), text.clone()));
+                        }
+                        ui.label(RichText::new(label).strong());
+                    });
+                    ui.add(egui::TextEdit::multiline(&mut text.as_str()).desired_rows(1)
+                        .desired_width(f32::INFINITY).font(egui::TextStyle::Monospace).interactive(false));
+                }
+                if let Some((label, text)) = copied {
+                    ctx.copy_text(text);
+                    self.set_status(&format!("✅ Copied {label}"), 2.0);
+                }
             });
             ui.add_space(4.0);
         });
@@ -624,10 +1723,45 @@ impl eframe::App for PromptPuppetApp {
         CentralPanel::default().show(ctx, |ui| {
             let sz = ui.available_size();
             let prev_dragging = self.dragging_joint_3d.clone();
-            let status_alpha = if self.status_timer > 0.5 { 1.0 } else { self.status_timer / 0.5 };
-            let status = (self.status_timer > 0.0).then(|| (self.status_message.as_str(), status_alpha));
-            let disco_time = self.dance_mode.then_some(self.dance_time);
-            draw_3d_canvas(ui, &mut self.state.pose, &mut self.camera_3d, sz, &mut self.dragging_joint_3d, status, disco_time);
+            let toasts: Vec<(&str, f32)> = self.toasts.iter()
+                .map(|(m, t)| (m.as_str(), if *t > 0.5 { 1.0 } else { t / 0.5 }))
+                .collect();
+            let disco_time = (self.dance_mode && self.loop_anim == crate::ftlz::LoopAnim::Dance)
+                .then_some(self.dance_time);
+            let pal = crate::canvas3d::Palette {
+                capsules: self.capsule_bones, guides: self.show_guides, lengths: self.show_bone_lengths,
+                facing: self.show_facing,
+                hidden: self.hidden_limbs.clone(),
+                ..self.canvas_theme.palette(self.dark_mode)
+            };
+            let resp = draw_3d_canvas(ui, &mut self.state.pose, &mut self.camera_3d, sz, &mut self.dragging_joint_3d, &toasts, disco_time, &pal, self.fk_drag, self.pin_root, &mut self.measure_joints,
+                self.reference_image.as_ref().map(|t| (t, self.reference_opacity)));
+            if self.show_pose_stats { draw_pose_stats(ui, &self.state.pose, &self.default_pose, resp.rect); }
+            // Right-click: one-shot mirror of a single limb from its partner,
+            // or straighten one while keeping where it points.
+            resp.context_menu(|ui| {
+                for (limb, label) in [("left_arm",  "🪞 Left arm ← right arm"),
+                                      ("right_arm", "🪞 Right arm ← left arm"),
+                                      ("left_leg",  "🪞 Left leg ← right leg"),
+                                      ("right_leg", "🪞 Right leg ← left leg")] {
+                    if ui.button(label).clicked() {
+                        self.state.pose.mirror_limb_from(limb, crate::skeleton::get());
+                        self.pose_is_manual = true;
+                        ui.close();
+                    }
+                }
+                ui.separator();
+                for (limb, label) in [("left_arm",  "📏 Straighten left arm"),
+                                      ("right_arm", "📏 Straighten right arm"),
+                                      ("left_leg",  "📏 Straighten left leg"),
+                                      ("right_leg", "📏 Straighten right leg")] {
+                    if ui.button(label).clicked() {
+                        self.state.pose.straighten_limb(limb);
+                        self.pose_is_manual = true;
+                        ui.close();
+                    }
+                }
+            });
             // A joint just started being dragged → switch to manual semantic prompt
             if self.dragging_joint_3d.is_some() && prev_dragging.is_none() {
                 self.pose_is_manual = true;
@@ -636,31 +1770,46 @@ impl eframe::App for PromptPuppetApp {
 
         handle_window_resize(ctx);
 
+        // ── Clipboard pose paste: button request, or Ctrl+V with no text field focused ──
+        let pasted = ctx.input(|i| i.events.iter().find_map(|e| match e {
+            egui::Event::Paste(s) => Some(s.clone()), _ => None,
+        }));
+        if let Some(text) = pasted {
+            let unfocused = ctx.memory(|m| m.focused().is_none());
+            if self.awaiting_paste > 0 || (unfocused && text.trim_start().starts_with('{')) {
+                self.paste_pose(&text);
+            }
+            self.awaiting_paste = 0;
+        }
+        self.awaiting_paste = self.awaiting_paste.saturating_sub(1);
+
         // ── 🕺 Dance Mode: Ctrl+Shift+D ───────────────────────────────────────
         let toggle_dance = ctx.input(|i| {
             i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(Key::D)
         });
-        if toggle_dance {
-            if self.dance_mode {
-                // Stop dancing — restore the pose we had before.
-                self.dance_mode = false;
-                self.dance_time = 0.0;
-                if let Some(saved) = self.pre_dance_pose.take() {
-                    self.state.pose = saved;
-                }
-                self.set_status("🛑 Dance mode off", 2.0);
-            } else {
-                // Start dancing — snapshot current pose so we can restore it later.
-                self.pre_dance_pose = Some(self.state.pose.clone());
-                self.dance_mode = true;
-                self.dance_time = 0.0;
-                self.set_status("🕺 Dance mode! (Ctrl+Shift+D to stop)", 3.0);
-            }
+        // Any other key press or click also ends the dance, so nobody gets
+        // stuck wondering how to get their pose back. (Panel loops have a
+        // visible Stop button and keep playing while the UI is used.)
+        let dancing = self.dance_mode && self.loop_anim == crate::ftlz::LoopAnim::Dance;
+        let interrupted = dancing && !toggle_dance && ctx.input(|i| i.events.iter().any(|e| matches!(e,
+            egui::Event::Key { pressed: true, .. } | egui::Event::PointerButton { pressed: true, .. })));
+        if (toggle_dance && self.dance_mode) || interrupted {
+            self.stop_dance();
+        } else if toggle_dance {
+            // Start dancing — snapshot current pose so we can restore it later.
+            self.start_loop(crate::ftlz::LoopAnim::Dance);
+            self.set_status("🕺 Dance mode! (any key or click to stop)", 3.0);
         }
         if self.dance_mode {
             let dt = ctx.input(|i| i.stable_dt).min(0.05); // cap to avoid jumps
             self.dance_time += dt;
-            crate::ftlz::apply_dance(&mut self.state.pose, &self.default_pose, self.dance_time);
+            // The dance is choreographed around the rest pose; the loops animate
+            // whatever pose the user started them from.
+            let base = match (self.loop_anim, &self.pre_dance_pose) {
+                (crate::ftlz::LoopAnim::Dance, _) | (_, None) => &self.default_pose,
+                (_, Some(pre)) => pre,
+            };
+            self.loop_anim.apply(&mut self.state.pose, base, self.dance_time);
             self.update_prompt();
             // Sync the hash so the bottom-of-frame hash check doesn't fire a
             // second update_prompt() — pose changed intentionally, already rebuilt.
@@ -676,27 +1825,33 @@ impl eframe::App for PromptPuppetApp {
         // AppState now implements Hash directly (sorted HashMap iteration +
         // allocation-free serde_json::Value hashing), so this is low-cost at idle.
         // During a joint drag the pose changes every frame, but rebuilding the prompt
-        // at 60fps is wasteful — the semantics description is throttled to ~150ms.
+        // at 60fps is wasteful — the semantics description is throttled to ~150ms,
+        // and whatever the throttle skipped is flushed once the drag is released
+        // so the prompt always ends on the final pose.
         let h = { let mut h = DefaultHasher::new(); self.state.hash(&mut h); h.finish() };
         if h != self.state_hash {
             self.state_hash = h;
-            let dt = ctx.input(|i| i.stable_dt);
-            let is_dragging = self.dragging_joint_3d.is_some();
-            if is_dragging {
-                self.prompt_throttle += dt;
-                if self.prompt_throttle >= 0.15 {
-                    self.prompt_throttle = 0.0;
-                    self.update_prompt();
-                }
-            } else {
+            self.prompt_dirty = true;
+        }
+        if self.dragging_joint_3d.is_some() {
+            self.prompt_throttle += ctx.input(|i| i.stable_dt);
+            if self.prompt_dirty && self.prompt_throttle >= 0.15 {
                 self.prompt_throttle = 0.0;
+                self.prompt_dirty = false;
                 self.update_prompt();
             }
+        } else if self.prompt_dirty {
+            self.prompt_throttle = 0.0;
+            self.prompt_dirty = false;
+            self.update_prompt();
         }
 
-        if self.status_timer > 0.0 {
-            self.status_timer -= ctx.input(|i| i.stable_dt);
-            if self.status_timer <= 0.0 { self.status_message.clear(); }
+        self.autosave(ctx);
+
+        if !self.toasts.is_empty() {
+            let dt = ctx.input(|i| i.stable_dt);
+            for (_, t) in &mut self.toasts { *t -= dt; }
+            self.toasts.retain(|(_, t)| *t > 0.0);
             ctx.request_repaint();
         }
     }
@@ -1,7 +1,8 @@
 // canvas3d.rs
 use egui::{Pos2, Vec2, Color32, Stroke, Rect, Ui, Response, Sense};
-use crate::pose::{Pose, Joint};
+use crate::pose::{Pose, Joint, FingerSet};
 use crate::skeleton::{self, Skeleton, color32};
+use std::collections::HashSet;
 
 #[derive(Clone, Debug)]
 pub struct Camera3D { pub focus: [f32;3], pub yaw: f32, pub pitch: f32, pub radius: f32, pub scale: f32 }
@@ -28,22 +29,130 @@ impl Camera3D {
     }
 }
 
-fn world(j: &Joint) -> [f32;3] { [j.x, j.y, j.z] }
+/// Canvas color scheme, chosen independently of the app's light/dark toggle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CanvasTheme { #[default] Default, HighContrast, Print }
 
-fn get<'a>(pose: &'a Pose, name: &str) -> Option<&'a Joint> {
-    Some(match name {
-        "head"           => &pose.head,          "neck"           => &pose.neck,
-        "left_shoulder"  => &pose.left_shoulder, "right_shoulder" => &pose.right_shoulder,
-        "left_elbow"     => &pose.left_elbow,    "right_elbow"    => &pose.right_elbow,
-        "left_wrist"     => &pose.left_wrist,    "right_wrist"    => &pose.right_wrist,
-        "waist"          => &pose.waist,          "crotch"         => &pose.crotch,
-        "left_knee"      => &pose.left_knee,      "right_knee"     => &pose.right_knee,
-        "left_ankle"     => &pose.left_ankle,     "right_ankle"    => &pose.right_ankle,
-        _ => return None,
-    })
+impl CanvasTheme {
+    pub const ALL: [CanvasTheme; 3] = [CanvasTheme::Default, CanvasTheme::HighContrast, CanvasTheme::Print];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            CanvasTheme::Default      => "Default",
+            CanvasTheme::HighContrast => "High contrast",
+            CanvasTheme::Print        => "Print",
+        }
+    }
+
+    /// Resolve to concrete colors. Only the default scheme follows the app theme.
+    pub fn palette(self, dark_mode: bool) -> Palette {
+        match self {
+            CanvasTheme::Default => Palette {
+                bg:   Color32::from_gray(if dark_mode { 18 } else { 80 }),
+                grid: Color32::from_gray(if dark_mode { 60 } else { 100 }),
+                ink: None, boost: false, bone_w: 4.0, gloss: true,
+                hover: Color32::from_rgba_premultiplied(255,255,255,170),
+                hint:  Color32::from_rgba_premultiplied(200,200,200,120),
+                capsules: false, guides: false, lengths: false, facing: false, hidden: HashSet::new(),
+            },
+            CanvasTheme::HighContrast => Palette {
+                bg: Color32::BLACK, grid: Color32::from_gray(70),
+                ink: None, boost: true, bone_w: 5.0, gloss: true,
+                hover: Color32::YELLOW,
+                hint:  Color32::from_gray(230),
+                capsules: false, guides: false, lengths: false, facing: false, hidden: HashSet::new(),
+            },
+            // White background, black skeleton — for screenshots and documentation.
+            CanvasTheme::Print => Palette {
+                bg: Color32::WHITE, grid: Color32::from_gray(215),
+                ink: Some(Color32::BLACK), boost: false, bone_w: 3.0, gloss: false,
+                hover: Color32::from_gray(120),
+                hint:  Color32::from_gray(110),
+                capsules: false, guides: false, lengths: false, facing: false, hidden: HashSet::new(),
+            },
+        }
+    }
+}
+
+/// Concrete canvas look for one frame (see `CanvasTheme::palette`).
+pub struct Palette {
+    pub bg:     Color32,
+    pub grid:   Color32,
+    /// Replaces every skeleton bone/joint color when set.
+    pub ink:    Option<Color32>,
+    /// Stretch skeleton colors to full brightness so thin bones don't wash out.
+    pub boost:  bool,
+    pub bone_w: f32,
+    /// Drop shadows and glossy joint highlights.
+    pub gloss:  bool,
+    pub hover:  Color32,
+    pub hint:   Color32,
+    /// Draw bones as thick round-capped capsules that scale with zoom, instead
+    /// of fixed thin lines. Set from the app's quality toggle, not the theme.
+    pub capsules: bool,
+    /// Rule-of-thirds, centre crosshair and ground line overlay. Also app-set.
+    pub guides: bool,
+    /// Label every bone with its length as a fraction of body height. App-set.
+    pub lengths: bool,
+    /// Gaze arrow from the head and a facing chevron on the chest. App-set.
+    pub facing: bool,
+    /// Limbs left out of the drawing (and of joint picking), e.g. to see the
+    /// torso behind the arms. App-set.
+    pub hidden: HashSet<Limb>,
+}
+
+/// Body part a joint or bone is drawn as part of, for hiding whole limbs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Limb { Head, Torso, LeftArm, RightArm, LeftLeg, RightLeg }
+
+impl Limb {
+    pub const ALL: [Limb; 6] = [Limb::Head, Limb::Torso, Limb::LeftArm, Limb::RightArm, Limb::LeftLeg, Limb::RightLeg];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Limb::Head     => "Head",
+            Limb::Torso    => "Torso",
+            Limb::LeftArm  => "Left arm",
+            Limb::RightArm => "Right arm",
+            Limb::LeftLeg  => "Left leg",
+            Limb::RightLeg => "Right leg",
+        }
+    }
+
+    /// Shoulders, neck, waist and crotch belong to the torso.
+    pub fn of(joint: &str) -> Limb {
+        match joint {
+            "head"                       => Limb::Head,
+            "left_elbow"  | "left_wrist"  => Limb::LeftArm,
+            "right_elbow" | "right_wrist" => Limb::RightArm,
+            "left_knee"   | "left_ankle"  => Limb::LeftLeg,
+            "right_knee"  | "right_ankle" => Limb::RightLeg,
+            _                            => Limb::Torso,
+        }
+    }
+
+    /// A bone joining the torso to a limb (upper arm, thigh, neck) goes with the limb.
+    fn of_bone(a: &str, b: &str) -> Limb {
+        match Limb::of(a) { Limb::Torso => Limb::of(b), l => l }
+    }
 }
 
-pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Vec2, drag: &mut Option<String>, status: Option<(&str, f32)>, disco_time: Option<f32>) -> Response {
+impl Palette {
+    fn tint(&self, c: Color32) -> Color32 {
+        if let Some(ink) = self.ink { return ink; }
+        if !self.boost { return c; }
+        let max = c.r().max(c.g()).max(c.b()).max(1) as f32;
+        let k = 255.0 / max;
+        Color32::from_rgb((c.r() as f32 * k) as u8, (c.g() as f32 * k) as u8, (c.b() as f32 * k) as u8)
+    }
+}
+
+fn world(j: &Joint) -> [f32;3] { [j.x, j.y, j.z] }
+
+
+#[allow(clippy::too_many_arguments)]
+pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Vec2, drag: &mut Option<String>, toasts: &[(&str, f32)], disco_time: Option<f32>, pal: &Palette, fk: bool, pin: bool, measure: &mut Vec<String>, backdrop: Option<(&egui::TextureHandle, f32)>) -> Response {
     let sk = skeleton::get();
     let (resp,p) = ui.allocate_painter(size, Sense::click_and_drag());
 
@@ -64,15 +173,21 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
         let hue   = (dt * 0.12).rem_euclid(1.0);              // full hue rotation ~8s
         let dark  = hsv(hue, 0.6, 0.07 + pulse * 0.04);      // very dark, hint of colour
         dark
-    } else if ui.visuals().dark_mode { Color32::from_gray(18) } else { Color32::from_gray(80) };
+    } else { pal.bg };
 
     p.rect_filled(resp.rect, 0.0, bg);
 
+    // Reference photo for tracing: scaled to fit, centred, under everything else.
+    if let Some((tex, opacity)) = backdrop {
+        let img = tex.size_vec2();
+        let fit = (resp.rect.width() / img.x).min(resp.rect.height() / img.y);
+        let rect = Rect::from_center_size(resp.rect.center(), img * fit);
+        let uv = Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0));
+        p.image(tex.id(), rect, uv, Color32::WHITE.gamma_multiply(opacity));
+    }
+
     // Calculate current figure bounds
-    let all = [&pose.head,&pose.neck,&pose.left_shoulder,&pose.right_shoulder,
-               &pose.left_elbow,&pose.right_elbow,&pose.left_wrist,&pose.right_wrist,
-               &pose.waist,&pose.crotch,&pose.left_knee,&pose.right_knee,
-               &pose.left_ankle,&pose.right_ankle];
+    let all: Vec<&Joint> = pose.joints_ordered().map(|(_, j)| j).collect();
     let (min_x,max_x) = all.iter().fold((f32::MAX,f32::MIN),|(lo,hi),j|(lo.min(j.x),hi.max(j.x)));
     let (min_y,max_y) = all.iter().fold((f32::MAX,f32::MIN),|(lo,hi),j|(lo.min(j.y),hi.max(j.y)));
     let (min_z,max_z) = all.iter().fold((f32::MAX,f32::MIN),|(lo,hi),j|(lo.min(j.z),hi.max(j.z)));
@@ -91,6 +206,15 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
     cam.focus[1] += (target_focus[1] - cam.focus[1]) * lerp_y;
     cam.focus[2] += (target_focus[2] - cam.focus[2]) * lerp_xz;
 
+    // Zoom limits and eye distance follow the figure's bounding-box diagonal,
+    // so a long-limbed or sprawled figure frames the same as the default one:
+    // on screen it may span 1/10 to 8× the canvas's shorter side.
+    let diag = ((max_x-min_x).powi(2) + (max_y-min_y).powi(2) + (max_z-min_z).powi(2)).sqrt().max(1.0);
+    let view = resp.rect.width().min(resp.rect.height()).max(1.0);
+    let (zoom_min, zoom_max) = (view * 0.1 / diag, view * 8.0 / diag);
+    cam.radius = cam.radius.max(diag * 2.2); // keep the eye outside the figure
+    if is_first_frame { cam.scale = (view * 0.8 / diag).clamp(zoom_min, zoom_max); }
+
     // View preset buttons
     let button_area = draw_view_buttons(ui, cam, resp.rect);
 
@@ -100,9 +224,18 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
     if just_pressed {
         if let Some(pos) = ui.input(|i| i.pointer.interact_pos()) {
             if !button_area.contains(pos) {
-                *drag = find_nearest(pose, &sk, cam, resp.rect, pos).map(str::to_owned);
+                *drag = find_nearest(pose, &sk, cam, resp.rect, pos, &pal.hidden).map(str::to_owned);
                 // drag == None means empty space → rotation mode
             }
+            // Shift+click picks joints for the measuring tape instead of dragging;
+            // a third pick replaces the oldest, and shift+click on empty space clears.
+            if ui.input(|i| i.modifiers.shift) {
+                match drag.take() {
+                    Some(name) if measure.contains(&name) => measure.retain(|m| *m != name),
+                    Some(name) => { measure.push(name); if measure.len() > 2 { measure.remove(0); } }
+                    None => measure.clear(),
+                }
+            }
         }
     }
     if resp.dragged() {
@@ -111,18 +244,21 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
         }
         if let Some(_pos) = resp.interact_pointer_pos() {
             match drag.as_ref() {
-                Some(name) => move_joint(pose, name, &sk, cam, resp.drag_delta()),
+                Some(name) => move_joint(pose, name, &sk, cam, resp.drag_delta(), fk, pin),
                 None => cam.yaw -= resp.drag_delta().x * 0.008,
             }
         }
     }
     if resp.drag_stopped() {
+        // Settle limbs out of the torso once, on release, rather than
+        // fighting the user every frame of the drag.
+        if drag.is_some() { pose.resolve_self_collision(sk); }
         *drag = None;
     }
     
     if resp.hovered() {
         let s = ui.input(|i| i.smooth_scroll_delta.y);
-        if s != 0.0 { cam.scale *= 1.0 + s*0.001; cam.scale = cam.scale.clamp(0.1, 10.0); }
+        if s != 0.0 { cam.scale *= 1.0 + s*0.001; cam.scale = cam.scale.clamp(zoom_min, zoom_max); }
     }
 
     // Draw XZ ground grid at floor level (feet_y already computed above)
@@ -156,7 +292,7 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
     }
 
     // ── Grid lines (rainbow in disco mode, plain otherwise) ──────────────────
-    let plain_grid = if ui.visuals().dark_mode { Color32::from_gray(60) } else { Color32::from_gray(100) };
+    let plain_grid = pal.grid;
 
     let mut line_idx = 0_u32;
     let mut x = center_x - grid_size;
@@ -195,41 +331,71 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
     }
     let _ = line_idx; // suppress unused warning
 
+    // Composition guide: ground line through the feet at the lower ankle's height,
+    // so it's obvious whether the figure is standing on the grid or hovering.
+    if pal.guides {
+        let gz = (pose.left_ankle.z + pose.right_ankle.z) / 2.0;
+        let g1 = cam.project([center_x - grid_size, feet_y, gz], resp.rect);
+        let g2 = cam.project([center_x + grid_size, feet_y, gz], resp.rect);
+        if let (Some((g1, _)), Some((g2, _))) = (g1, g2) {
+            p.line_segment([g1, g2], Stroke::new(2.0, pal.hint));
+        }
+    }
+
     // Determine which joint is under cursor for hover highlight
     let hovered_joint: Option<&str> = if drag.is_some() {
         drag.as_deref()
     } else {
         ui.input(|i| i.pointer.hover_pos())
             .filter(|pos| resp.rect.contains(*pos) && !button_area.contains(*pos))
-            .and_then(|pos| find_nearest(pose, &sk, cam, resp.rect, pos))
+            .and_then(|pos| find_nearest(pose, &sk, cam, resp.rect, pos, &pal.hidden))
     };
 
-    struct Draw { a:Pos2, b:Pos2, z:f32, c:Color32, is_j:bool, r:f32, hovered:bool }
+    struct Draw { a:Pos2, b:Pos2, z:f32, c:Color32, is_j:bool, r:f32, hovered:bool, w:f32 }
     let mut draws: Vec<Draw> = Vec::new();
 
     for bone in &sk.bones {
-        if let (Some(ja),Some(jb)) = (get(pose,&bone.a),get(pose,&bone.b)) {
+        if pal.hidden.contains(&Limb::of_bone(&bone.a, &bone.b)) { continue; }
+        if let (Some(ja),Some(jb)) = (pose.joint(&bone.a),pose.joint(&bone.b)) {
             if let (Some((pa,za)),Some((pb,zb))) = (cam.project(world(ja),resp.rect),cam.project(world(jb),resp.rect)) {
                 let c = if let Some(dt) = disco_time {
                     // Each bone gets its own hue offset so the skeleton is fully rainbow
                     let bone_hash = bone.a.len() as f32 * 0.07 + bone.b.len() as f32 * 0.13;
                     let hue = (dt * 0.22 + bone_hash).rem_euclid(1.0);
                     hsv(hue, 1.0, 1.0)
-                } else { color32(bone.color) };
-                draws.push(Draw{a:pa,b:pb,z:(za+zb)*0.5,c,is_j:false,r:0.0,hovered:false});
+                } else { pal.tint(color32(bone.color)) };
+                draws.push(Draw{a:pa,b:pb,z:(za+zb)*0.5,c,is_j:false,r:0.0,hovered:false,w:0.0});
+            }
+        }
+    }
+    // ── Finger fans: five schematic segments per wrist from FingerSet ────────
+    let finger_len = sk.head_size * 0.45;
+    for (el, wr, fs, sign, wname) in [
+        (&pose.left_elbow,  &pose.left_wrist,  &pose.left_fingers,  -1.0, "left_wrist"),
+        (&pose.right_elbow, &pose.right_wrist, &pose.right_fingers,  1.0, "right_wrist"),
+    ] {
+        if pal.hidden.contains(&Limb::of(wname)) { continue; }
+        let base = pal.tint(sk.joints.iter().find(|j| j.name == wname).map_or(Color32::GRAY, |j| color32(j.color)));
+        for (i, (a, b)) in finger_segments(el, wr, fs, sign, finger_len).into_iter().enumerate() {
+            if let (Some((pa,za)),Some((pb,zb))) = (cam.project(a,resp.rect),cam.project(b,resp.rect)) {
+                let c = if let Some(dt) = disco_time {
+                    hsv((dt * 0.3 + i as f32 * 0.2).rem_euclid(1.0), 0.8, 1.0)
+                } else { base };
+                draws.push(Draw{a:pa,b:pb,z:(za+zb)*0.5,c,is_j:false,r:0.0,hovered:false,w:1.5});
             }
         }
     }
     for jd in &sk.joints {
-        if let Some(j) = get(pose,&jd.name) {
+        if pal.hidden.contains(&Limb::of(&jd.name)) { continue; }
+        if let Some(j) = pose.joint(&jd.name) {
             if let Some((pos,z)) = cam.project(world(j),resp.rect) {
                 let is_hov = hovered_joint == Some(jd.name.as_str());
                 let c = if let Some(dt) = disco_time {
                     let joint_hash = jd.name.len() as f32 * 0.11;
                     let hue = (dt * 0.3 + joint_hash).rem_euclid(1.0);
                     hsv(hue, 0.8, 1.0)
-                } else { color32(jd.color) };
-                draws.push(Draw{a:pos,b:pos,z,c,is_j:true,r:jd.radius*1.5,hovered:is_hov});
+                } else { pal.tint(color32(jd.color)) };
+                draws.push(Draw{a:pos,b:pos,z,c,is_j:true,r:jd.radius*1.5,hovered:is_hov,w:0.0});
             }
         }
     }
@@ -237,28 +403,108 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
     for d in draws {
         if d.is_j {
             if d.hovered {
-                p.circle_filled(d.a, d.r + 7.0, Color32::from_rgba_premultiplied(255,255,255,25));
-                p.circle_stroke(d.a, d.r + 5.0, Stroke::new(2.0, Color32::from_rgba_premultiplied(255,255,255,170)));
+                if pal.gloss { p.circle_filled(d.a, d.r + 7.0, Color32::from_rgba_premultiplied(255,255,255,25)); }
+                p.circle_stroke(d.a, d.r + 5.0, Stroke::new(2.0, pal.hover));
             }
             // In disco mode joints pulse in size with the beat
             let r = if let Some(dt) = disco_time {
                 let pulse = (dt * 140.0 / 60.0 * std::f32::consts::TAU * 2.0).sin() * 0.22 + 1.0;
                 d.r * pulse
             } else { d.r };
-            p.circle_filled(d.a+Vec2::new(1.5,2.0), r+1.0, Color32::from_black_alpha(60));
+            if pal.gloss { p.circle_filled(d.a+Vec2::new(1.5,2.0), r+1.0, Color32::from_black_alpha(60)); }
             p.circle_filled(d.a, r, d.c);
-            let rim_w = if d.hovered { 2.5 } else { 1.5 };
-            let rim_a = if d.hovered { 220 } else { 80 };
-            p.circle_stroke(d.a, r, Stroke::new(rim_w, Color32::from_rgba_premultiplied(255,255,255,rim_a)));
-            p.circle_filled(d.a+Vec2::new(-r*0.3,-r*0.35), r*0.35, Color32::from_rgba_premultiplied(255,255,255,160));
+            if pal.gloss {
+                let rim_w = if d.hovered { 2.5 } else { 1.5 };
+                let rim_a = if d.hovered { 220 } else { 80 };
+                p.circle_stroke(d.a, r, Stroke::new(rim_w, Color32::from_rgba_premultiplied(255,255,255,rim_a)));
+                p.circle_filled(d.a+Vec2::new(-r*0.3,-r*0.35), r*0.35, Color32::from_rgba_premultiplied(255,255,255,160));
+            }
+        } else if d.w > 0.0 {
+            p.line_segment([d.a,d.b], Stroke::new(d.w, d.c));
         } else {
             let stroke_w = if let Some(dt) = disco_time {
                 // Bones throb on the beat
                 let pulse = (dt * 140.0 / 60.0 * std::f32::consts::TAU).sin() * 1.5 + 4.0;
                 pulse
-            } else { 4.0 };
-            p.line_segment([d.a+Vec2::new(1.5,2.0),d.b+Vec2::new(1.5,2.0)], Stroke::new(stroke_w+1.0,Color32::from_black_alpha(60)));
+            } else { pal.bone_w };
+            // Capsules: ~2.5× thicker at the default zoom (scale 1.6), following zoom.
+            let stroke_w = if pal.capsules { stroke_w * 2.5 * (cam.scale / 1.6).clamp(0.6, 2.5) } else { stroke_w };
+            if pal.gloss {
+                p.line_segment([d.a+Vec2::new(1.5,2.0),d.b+Vec2::new(1.5,2.0)], Stroke::new(stroke_w+1.0,Color32::from_black_alpha(60)));
+            }
             p.line_segment([d.a,d.b], Stroke::new(stroke_w, d.c));
+            if pal.capsules {
+                p.circle_filled(d.a, stroke_w * 0.5, d.c);
+                p.circle_filled(d.b, stroke_w * 0.5, d.c);
+            }
+        }
+    }
+
+    // ── Facing (🧭 toggle): gaze arrow from the head, chevron on the chest ────
+    // Body forward is perpendicular to the shoulder line in the floor plane,
+    // +Z (toward the viewer) for the rest pose, +X once the body turns right.
+    // The gaze turns that by head_yaw toward the character's right and drops
+    // it by head_nod (Y down).
+    if pal.facing {
+        let (ls, rs) = (&pose.left_shoulder, &pose.right_shoulder);
+        let (rx, rz) = (rs.x - ls.x, rs.z - ls.z);
+        let len = (rx*rx + rz*rz).sqrt();
+        if len > 1e-3 {
+            let (rx, rz) = (rx / len, rz / len);
+            let (fx, fz) = (-rz, rx);
+            let reach = sk.head_size * 1.6;
+            let tip = |from: [f32;3], d: [f32;3], k: f32| [from[0] + d[0]*k, from[1] + d[1]*k, from[2] + d[2]*k];
+            if !pal.hidden.contains(&Limb::Head) {
+                let ((sy, cy), (sn, cn)) = (pose.head_yaw.to_radians().sin_cos(), pose.head_nod.to_radians().sin_cos());
+                let gaze = [(fx*cy + rx*sy) * cn, sn, (fz*cy + rz*sy) * cn];
+                let head = world(&pose.head);
+                if let (Some((a, _)), Some((b, _))) = (cam.project(head, resp.rect), cam.project(tip(head, gaze, reach), resp.rect)) {
+                    p.arrow(a, b - a, Stroke::new(2.0, pal.hover));
+                }
+            }
+            if !pal.hidden.contains(&Limb::Torso) {
+                let (n, w) = (world(&pose.neck), world(&pose.waist));
+                let chest = [(n[0]+w[0])*0.5, (n[1]+w[1])*0.5, (n[2]+w[2])*0.5];
+                let fwd = [fx, 0.0, fz];
+                let point = tip(chest, fwd, reach * 0.6);
+                let wing = |side: f32| tip(tip(chest, fwd, reach * 0.25), [rx, 0.0, rz], side * reach * 0.35);
+                let pts: Vec<Pos2> = [wing(-1.0), point, wing(1.0)].into_iter()
+                    .filter_map(|w| cam.project(w, resp.rect).map(|(q, _)| q)).collect();
+                if pts.len() == 3 { p.add(egui::Shape::line(pts, Stroke::new(2.5, pal.hint))); }
+            }
+        }
+    }
+
+    // ── Bone lengths (📏 toggle): fraction of body height at each midpoint ────
+    if pal.lengths {
+        let height = (feet_y - pose.head.y).abs().max(1.0);
+        for bone in &sk.bones {
+            if pal.hidden.contains(&Limb::of_bone(&bone.a, &bone.b)) { continue; }
+            let (Some(ja), Some(jb)) = (pose.joint(&bone.a), pose.joint(&bone.b)) else { continue };
+            if let (Some((pa, _)), Some((pb, _))) = (cam.project(world(ja), resp.rect), cam.project(world(jb), resp.rect)) {
+                let (dx, dy, dz) = (jb.x - ja.x, jb.y - ja.y, jb.z - ja.z);
+                let len = (dx*dx + dy*dy + dz*dz).sqrt() / height;
+                value_tag(&p, pa + (pb - pa) * 0.5, format!("{len:.2}"), 10.0, pal);
+            }
+        }
+    }
+
+    // ── Measuring tape between two shift-clicked joints ──────────────────────
+    if let [a, b] = measure.as_slice() {
+        if let (Some(ja), Some(jb)) = (pose.joint(a), pose.joint(b)) {
+            if let (Some((pa, _)), Some((pb, _))) = (cam.project(world(ja), resp.rect), cam.project(world(jb), resp.rect)) {
+                let st = Stroke::new(1.5, pal.hover);
+                p.extend(egui::Shape::dashed_line(&[pa, pb], st, 6.0, 4.0));
+                for e in [pa, pb] { p.circle_stroke(e, 9.0, st); }
+                // Length as a fraction of body height (head to floor); angle of
+                // the segment above the floor plane, independent of the view.
+                let height = (feet_y - pose.head.y).abs().max(1.0);
+                let (dx, dy, dz) = (jb.x - ja.x, jb.y - ja.y, jb.z - ja.z);
+                let len = (dx*dx + dy*dy + dz*dz).sqrt();
+                let angle = dy.abs().atan2((dx*dx + dz*dz).sqrt()).to_degrees();
+                let label = format!("{:.2} × height  ·  {:.0}° from horizontal", len / height, angle);
+                value_tag(&p, pa + (pb - pa) * 0.5 + Vec2::new(0.0, -14.0), label, 12.0, pal);
+            }
         }
     }
 
@@ -284,38 +530,153 @@ pub fn draw_3d_canvas(ui: &mut Ui, pose: &mut Pose, cam: &mut Camera3D, size: Ve
             p.line_segment([Pos2::new(sx,sy-arm), Pos2::new(sx,sy+arm)], Stroke::new(1.0, sc));
         }
     }
-    p.text(resp.rect.min+Vec2::new(8.,6.), egui::Align2::LEFT_TOP,
-        if drag.is_some() {"Dragging joint..."} else {"Drag joint: move   Drag empty: rotate   Scroll: zoom"},
-        egui::FontId::proportional(11.0), Color32::from_rgba_premultiplied(200,200,200,120));
-
-    // ── Status toast (upper-right corner) ────────────────────────────────────
-    if let Some((msg, alpha)) = status {
-        if alpha > 0.0 {
-            let a = (alpha * 255.0).round() as u8;
-            let pad = Vec2::new(12.0, 8.0);
-            let font = egui::FontId::proportional(13.0);
-            let galley = ui.painter().layout_no_wrap(
-                msg.to_string(), font.clone(), Color32::WHITE);
-            let text_size = galley.size();
-            let bg_size   = text_size + pad * 2.0;
-            let bg_pos    = egui::Pos2::new(
-                resp.rect.max.x - bg_size.x - 10.0,
-                resp.rect.min.y + 10.0,
-            );
-            let bg_rect = egui::Rect::from_min_size(bg_pos, bg_size);
-            p.rect_filled(bg_rect, 6.0,
-                Color32::from_rgba_premultiplied(20, 20, 20, (a as f32 * 0.82) as u8));
-            p.rect_stroke(bg_rect, 6.0,
-                egui::Stroke::new(1.0, Color32::from_rgba_premultiplied(255, 255, 255, (a as f32 * 0.18) as u8)),
-                egui::StrokeKind::Outside);
-            p.text(bg_pos + pad, egui::Align2::LEFT_TOP,
-                msg, font, Color32::from_rgba_premultiplied(255, 255, 255, a));
+    // Rule-of-thirds lines and a centre crosshair, for framing before copying.
+    if pal.guides {
+        let r = resp.rect;
+        let st = Stroke::new(1.0, pal.hint.gamma_multiply(0.5));
+        for k in [1.0/3.0, 2.0/3.0] {
+            let x = r.min.x + r.width() * k;
+            let y = r.min.y + r.height() * k;
+            p.line_segment([Pos2::new(x, r.min.y), Pos2::new(x, r.max.y)], st);
+            p.line_segment([Pos2::new(r.min.x, y), Pos2::new(r.max.x, y)], st);
         }
+        let c = r.center();
+        p.line_segment([c - Vec2::new(12.0, 0.0), c + Vec2::new(12.0, 0.0)], Stroke::new(1.0, pal.hint));
+        p.line_segment([c - Vec2::new(0.0, 12.0), c + Vec2::new(0.0, 12.0)], Stroke::new(1.0, pal.hint));
+    }
+    p.text(resp.rect.min+Vec2::new(8.,6.), egui::Align2::LEFT_TOP,
+        if drag.is_some() {"Dragging joint..."} else {"Drag joint: move   Drag empty: rotate   Scroll: zoom   Shift+click: measure"},
+        egui::FontId::proportional(11.0), pal.hint);
+
+    // ── Status toasts (upper-right corner), newest on top ─────────────────────
+    let mut y = resp.rect.min.y + 10.0;
+    for &(msg, alpha) in toasts.iter().rev() {
+        if alpha <= 0.0 { continue; }
+        let a = (alpha * 255.0).round() as u8;
+        let pad = Vec2::new(12.0, 8.0);
+        let font = egui::FontId::proportional(13.0);
+        let galley = ui.painter().layout_no_wrap(
+            msg.to_string(), font.clone(), Color32::WHITE);
+        let text_size = galley.size();
+        let bg_size   = text_size + pad * 2.0;
+        let bg_pos    = egui::Pos2::new(resp.rect.max.x - bg_size.x - 10.0, y);
+        let bg_rect = egui::Rect::from_min_size(bg_pos, bg_size);
+        p.rect_filled(bg_rect, 6.0,
+            Color32::from_rgba_premultiplied(20, 20, 20, (a as f32 * 0.82) as u8));
+        p.rect_stroke(bg_rect, 6.0,
+            egui::Stroke::new(1.0, Color32::from_rgba_premultiplied(255, 255, 255, (a as f32 * 0.18) as u8)),
+            egui::StrokeKind::Outside);
+        p.text(bg_pos + pad, egui::Align2::LEFT_TOP,
+            msg, font, Color32::from_rgba_premultiplied(255, 255, 255, a));
+        y += bg_size.y + 6.0;
     }
 
     resp
 }
 
+/// Small readout centred on `at`, on a translucent plate of the canvas
+/// background so it stays legible over bones and grid.
+fn value_tag(p: &egui::Painter, at: Pos2, text: String, size: f32, pal: &Palette) {
+    let galley = p.layout_no_wrap(text, egui::FontId::proportional(size), pal.hover);
+    let bg = Rect::from_center_size(at, galley.size() + Vec2::new(10.0, 6.0));
+    p.rect_filled(bg, 4.0, pal.bg.gamma_multiply(0.85));
+    p.galley(bg.min + Vec2::new(5.0, 3.0), galley, pal.hover);
+}
+
+/// Rasterize the pose from the canvas camera's angle into a `size`×`size`
+/// image, without egui. The figure is refitted to fill ~80% of the frame, so
+/// the canvas zoom doesn't matter. Same depth ordering and colors as the live
+/// canvas; no grid, gloss or overlays.
+pub fn render_image(pose: &Pose, cam: &Camera3D, size: u32, pal: &Palette) -> image::RgbaImage {
+    let sk = skeleton::get();
+    let unit = Camera3D { scale: 1.0, ..cam.clone() };
+    let r = Rect::from_min_size(Pos2::ZERO, Vec2::splat(size as f32));
+    let proj = |w: [f32;3]| unit.project(w, r);
+
+    // Fit: bounding box of the projected joints at scale 1, then scale about its centre.
+    let pts: Vec<Pos2> = pose.joints_ordered().filter_map(|(_, j)| proj(world(j))).map(|(p, _)| p).collect();
+    let bb = Rect::from_points(&pts);
+    let k = if pts.is_empty() { 1.0 } else { size as f32 * 0.8 / bb.width().max(bb.height()).max(1.0) };
+    let fit = |p: Pos2| r.center() + (p - bb.center()) * k;
+
+    // Bones/joints drawn back to front, as in draw_3d_canvas.
+    let px = size as f32 / 600.0;
+    let bone_w = pal.bone_w * px * if pal.capsules { 2.5 } else { 1.0 };
+    let mut draws: Vec<(f32, Pos2, Pos2, f32, Color32)> = Vec::new();
+    for bone in &sk.bones {
+        if pal.hidden.contains(&Limb::of_bone(&bone.a, &bone.b)) { continue; }
+        if let (Some(ja), Some(jb)) = (pose.joint(&bone.a), pose.joint(&bone.b)) {
+            if let (Some((a, za)), Some((b, zb))) = (proj(world(ja)), proj(world(jb))) {
+                draws.push(((za+zb)*0.5, fit(a), fit(b), bone_w * 0.5, pal.tint(color32(bone.color))));
+            }
+        }
+    }
+    for jd in &sk.joints {
+        if pal.hidden.contains(&Limb::of(&jd.name)) { continue; }
+        if let Some((pos, z)) = pose.joint(&jd.name).and_then(|j| proj(world(j))) {
+            let pos = fit(pos);
+            draws.push((z, pos, pos, jd.radius * 1.5 * px, pal.tint(color32(jd.color))));
+        }
+    }
+    draws.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+
+    let bg = pal.bg;
+    let mut img = image::RgbaImage::from_pixel(size, size, image::Rgba([bg.r(), bg.g(), bg.b(), 255]));
+    for (_, a, b, rad, c) in draws { stamp_segment(&mut img, a, b, rad, c); }
+    img
+}
+
+/// Fill every pixel within `rad` of segment a–b (a disc when a == b), with a
+/// one-pixel soft edge.
+fn stamp_segment(img: &mut image::RgbaImage, a: Pos2, b: Pos2, rad: f32, c: Color32) {
+    let (w, h) = (img.width() as i32, img.height() as i32);
+    let x0 = ((a.x.min(b.x) - rad - 1.0).floor() as i32).max(0);
+    let x1 = ((a.x.max(b.x) + rad + 1.0).ceil()  as i32).min(w - 1);
+    let y0 = ((a.y.min(b.y) - rad - 1.0).floor() as i32).max(0);
+    let y1 = ((a.y.max(b.y) + rad + 1.0).ceil()  as i32).min(h - 1);
+    let ab = b - a;
+    let len2 = ab.length_sq().max(1e-6);
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            let q = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+            let t = ((q - a).dot(ab) / len2).clamp(0.0, 1.0);
+            let cov = (rad + 0.5 - q.distance(a + ab * t)).clamp(0.0, 1.0);
+            if cov <= 0.0 { continue; }
+            let px = img.get_pixel_mut(x as u32, y as u32);
+            let alpha = cov * c.a() as f32 / 255.0;
+            for (dst, src) in px.0.iter_mut().zip([c.r(), c.g(), c.b()]) {
+                *dst = (*dst as f32 + (src as f32 - *dst as f32) * alpha).round() as u8;
+            }
+        }
+    }
+}
+
+/// World-space segments for a schematic hand: thumb → pinky fanned around the
+/// forearm direction. `spread` opens the fan (degrees); each finger's curl
+/// (0 = straight, 90 = folded) shortens it and bends it toward the palm.
+/// `sign`: −1 left hand, +1 right hand, so the thumb always sits on the inside.
+fn finger_segments(el: &Joint, wr: &Joint, f: &FingerSet, sign: f32, len: f32) -> [([f32;3],[f32;3]); 5] {
+    let norm = |v: [f32;3]| { let m = (v[0]*v[0]+v[1]*v[1]+v[2]*v[2]).sqrt().max(1e-6); [v[0]/m, v[1]/m, v[2]/m] };
+    let cross = |a: [f32;3], b: [f32;3]| [a[1]*b[2]-a[2]*b[1], a[2]*b[0]-a[0]*b[2], a[0]*b[1]-a[1]*b[0]];
+    let w = world(wr);
+    let dir = norm([wr.x-el.x, wr.y-el.y, wr.z-el.z]);
+    // Fan axis perpendicular to the forearm; fall back to X when the forearm points along Z.
+    let lat = cross(dir, [0.0, 0.0, 1.0]);
+    let lat = if lat[0].abs() + lat[1].abs() < 1e-3 { [1.0, 0.0, 0.0] } else { norm(lat) };
+    let palm = cross(dir, lat);
+    let curls = [f.thumb, f.index, f.middle, f.ring, f.pinky];
+    std::array::from_fn(|i| {
+        // Thumb gets an extra 25° so it splays away from the other four.
+        let off = (i as f32 - 2.0) * f.spread * 0.5 - if i == 0 { 25.0 } else { 0.0 };
+        let (so, co) = (off * sign).to_radians().sin_cos();
+        let curl = curls[i].clamp(0.0, 90.0).to_radians();
+        let (sc, cc) = (curl * 0.5).sin_cos();
+        let l = len * (1.0 - curls[i].clamp(0.0, 90.0) / 90.0 * 0.6) * if i == 0 { 0.8 } else { 1.0 };
+        let d = [0, 1, 2].map(|k| (dir[k]*co + lat[k]*so) * cc + palm[k] * sc);
+        (w, [w[0]+d[0]*l, w[1]+d[1]*l, w[2]+d[2]*l])
+    })
+}
+
 fn draw_view_buttons(ui: &mut Ui, cam: &mut Camera3D, rect: Rect) -> Rect {
     let btn_size = Vec2::new(54.0, 28.0);
     let spacing = 6.0;
@@ -392,13 +753,14 @@ fn draw_view_buttons(ui: &mut Ui, cam: &mut Camera3D, rect: Rect) -> Rect {
     button_area
 }
 
-fn find_nearest<'a>(pose: &Pose, sk: &'a Skeleton, cam: &Camera3D, r: Rect, pos: Pos2) -> Option<&'a str> {
+fn find_nearest<'a>(pose: &Pose, sk: &'a Skeleton, cam: &Camera3D, r: Rect, pos: Pos2, hidden: &HashSet<Limb>) -> Option<&'a str> {
     // Hit radius scales with zoom so joints are equally clickable when zoomed out.
     // Minimum 14px so tiny/distant joints are still reachable.
     let zoom_scale = cam.scale.clamp(0.5, 3.0);
     let mut best: Option<(usize, f32, f32)> = None; // (idx, dist, z)
     for (i, jd) in sk.joints.iter().enumerate() {
-        let Some((sp, z)) = cam.project(world(get(pose, &jd.name)?), r) else { continue };
+        if hidden.contains(&Limb::of(&jd.name)) { continue; }
+        let Some((sp, z)) = cam.project(world(pose.joint(&jd.name)?), r) else { continue };
         let dist = sp.distance(pos);
         let hit_radius = (jd.radius * 1.5 * zoom_scale + 6.0).max(14.0);
         if dist < hit_radius {
@@ -410,8 +772,8 @@ fn find_nearest<'a>(pose: &Pose, sk: &'a Skeleton, cam: &Camera3D, r: Rect, pos:
     best.map(|(i, _, _)| sk.joints[i].name.as_str())
 }
 
-fn move_joint(pose: &mut Pose, name: &str, sk: &Skeleton, cam: &Camera3D, delta: Vec2) {
-    let Some(j_ref) = get(pose, name) else { return };
+fn move_joint(pose: &mut Pose, name: &str, sk: &Skeleton, cam: &Camera3D, delta: Vec2, fk: bool, pin: bool) {
+    let Some(j_ref) = pose.joint(name) else { return };
 
     // Delta-based movement: convert the tiny per-frame screen delta into a world nudge.
     // This is fundamentally smoother than absolute-position tracking because:
@@ -432,5 +794,7 @@ fn move_joint(pose: &mut Pose, name: &str, sk: &Skeleton, cam: &Camera3D, delta:
     let cur = world(j_ref);
     let target = (cur[0]+wx, cur[1]+wy, cur[2]+wz);
 
-    pose.move_joint(name, target, sk);
+    if fk { pose.rotate_joint(name, target, sk); }
+    else if pin { pose.move_joint_pinned(name, target, sk); }
+    else { pose.move_joint(name, target, sk); }
 }
\ No newline at end of file
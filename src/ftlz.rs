@@ -1,10 +1,10 @@
 // ftlz.rs — 🕺 For The Lulz.  Ctrl+Shift+D.  You didn't see anything.
 //
-// Axes (pose.rs convention):  X = left→right,  Y = bottom→top (up = positive),  Z = toward viewer.
+// Axes (pose.rs convention):  X = left→right,  Y = bottom→top (up = positive),  +Z = forward, toward the viewer.
 // All offsets are relative to `base` (the rest pose), so the animation is
 // scale-independent and doesn't care where the default pose sits in world space.
 
-use crate::pose::Pose;
+use crate::pose::{Joint, Pose};
 use std::f32::consts::TAU;
 
 pub fn apply_dance(pose: &mut Pose, base: &Pose, t: f32) {
@@ -102,15 +102,15 @@ pub fn apply_dance(pose: &mut Pose, base: &Pose, t: f32) {
 
     // ── Legs: alternating high-knee running-man kicks ─────────────────────────
     //
-    // The motion: knee drives UP and FORWARD (−Z toward viewer), while the
-    // ankle swings BACK (+Z away) — classic running / funky-chicken style.
+    // The motion: knee drives UP and FORWARD (+Z toward viewer), while the
+    // ankle swings BACK (−Z away) — classic running / funky-chicken style.
     // We use the positive lobe of sin so each leg only kicks on its own half-beat.
     // powf(0.65) softens the hard edge a bit → smoother rise and fall.
     //
     // The crotch already has hip sway, so knees drift with it naturally.
 
     let kick_h  = 38.0;    // knee lift height
-    let kick_fwd = 18.0;   // knee forward throw (−Z)
+    let kick_fwd = 18.0;   // knee forward throw (+Z)
     let kick_sx  = 10.0;   // slight inward pull on the kicking leg
 
     let raw_l  =  sin(p1).max(0.0);            // 0..1, left kicks on beat 1
@@ -121,24 +121,24 @@ pub fn apply_dance(pose: &mut Pose, base: &Pose, t: f32) {
     // LEFT knee ── up + forward when kicking
     pose.left_knee.x = base.left_knee.x + hip_sway * 0.35 - kick_sx * kl;
     pose.left_knee.y = base.left_knee.y + kick_h  * kl + bounce * 0.25;
-    pose.left_knee.z = base.left_knee.z - kick_fwd * kl;    // toward viewer
+    pose.left_knee.z = base.left_knee.z + kick_fwd * kl;    // toward viewer
 
     // LEFT ankle ── swings BACK as knee comes forward (like a real stride)
     let alk    = (sin(p1 - 0.55)).max(0.0).powf(0.65);   // ankle lags knee by ~0.55 rad
     pose.left_ankle.x = base.left_ankle.x + hip_sway * 0.20;
     pose.left_ankle.y = base.left_ankle.y + 10.0 * alk;   // slight lift
-    pose.left_ankle.z = base.left_ankle.z + 28.0 * kl;    // foot kicks back (+Z) strongly
+    pose.left_ankle.z = base.left_ankle.z - 28.0 * kl;    // foot kicks back (−Z) strongly
 
     // RIGHT knee ── mirror of left
     pose.right_knee.x = base.right_knee.x + hip_sway * 0.35 + kick_sx * kr;
     pose.right_knee.y = base.right_knee.y + kick_h  * kr + bounce * 0.25;
-    pose.right_knee.z = base.right_knee.z - kick_fwd * kr;
+    pose.right_knee.z = base.right_knee.z + kick_fwd * kr;
 
     // RIGHT ankle
     let ark    = (-sin(p1 - 0.55)).max(0.0).powf(0.65);
     pose.right_ankle.x = base.right_ankle.x + hip_sway * 0.20;
     pose.right_ankle.y = base.right_ankle.y + 10.0 * ark;
-    pose.right_ankle.z = base.right_ankle.z + 28.0 * kr;
+    pose.right_ankle.z = base.right_ankle.z - 28.0 * kr;
 
     // ── Subtle heel-click on the off-beat ─────────────────────────────────────
     // Both ankles briefly come together at the top of a small hop every 4 beats.
@@ -157,4 +157,131 @@ pub fn apply_dance(pose: &mut Pose, base: &Pose, t: f32) {
         pose.right_ankle.x += click_inward;
         pose.right_ankle.y += click_up;
     }
-}
\ No newline at end of file
+}
+// ── Video loops ───────────────────────────────────────────────────────────────
+//
+// Less silly siblings of the dance, picked from the keyframe panel in video
+// mode and driven by the same clock. Unlike apply_dance these keep bone
+// lengths: limbs are swung as angles about their root. Note Y grows downward
+// on screen (semantics.rs), so "up" is −Y; +Z is forward, as in describe_leg.
+
+/// Procedural loop animations. `Dance` is the Ctrl+Shift+D easter egg; the
+/// rest are offered in the keyframe panel.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LoopAnim { Dance, #[default] Breathing, Waving, Walk }
+
+impl LoopAnim {
+    /// The loops offered in the UI (the dance stays a hotkey secret).
+    pub const VIDEO: [LoopAnim; 3] = [LoopAnim::Breathing, LoopAnim::Waving, LoopAnim::Walk];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LoopAnim::Dance     => "Dance",
+            LoopAnim::Breathing => "Idle breathing",
+            LoopAnim::Waving    => "Waving",
+            LoopAnim::Walk      => "Walk cycle",
+        }
+    }
+
+    /// Seconds for one full cycle — used to spread baked keyframes evenly.
+    pub fn period(self) -> f32 {
+        match self {
+            LoopAnim::Dance     => 4.0 * 60.0 / 140.0, // one bar at 140 bpm
+            LoopAnim::Breathing => BREATH_PERIOD,
+            LoopAnim::Waving    => WAVE_PERIOD,
+            LoopAnim::Walk      => WALK_PERIOD,
+        }
+    }
+
+    pub fn apply(self, pose: &mut Pose, base: &Pose, t: f32) {
+        match self {
+            LoopAnim::Dance     => apply_dance(pose, base, t),
+            LoopAnim::Breathing => apply_breathing(pose, base, t),
+            LoopAnim::Waving    => apply_wave(pose, base, t),
+            LoopAnim::Walk      => apply_walk(pose, base, t),
+        }
+    }
+}
+
+const BREATH_PERIOD: f32 = 4.0;
+const WAVE_PERIOD:   f32 = 0.7;
+const WALK_PERIOD:   f32 = 1.1;   // two steps
+
+type P3 = (f32, f32, f32);
+
+fn dist(a: &Joint, b: &Joint) -> f32 {
+    ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
+}
+
+/// Hang a two-bone limb off `root` in its sagittal plane: the upper bone at
+/// `a1` radians from straight down (+ = forward), the lower bone bent a further
+/// `a2`. The base pose's sideways offsets are kept, so arms stay clear of the
+/// body and feet keep their width.
+fn swing_limb(root: P3, base_root: &Joint, mid: &Joint, end: &Joint, a1: f32, a2: f32) -> (P3, P3) {
+    let (dx1, dx2) = (mid.x - base_root.x, end.x - mid.x);
+    let l1 = (dist(base_root, mid).powi(2) - dx1 * dx1).max(1.0).sqrt();
+    let l2 = (dist(mid, end).powi(2) - dx2 * dx2).max(1.0).sqrt();
+    let m = (root.0 + dx1, root.1 + l1 * a1.cos(), root.2 + l1 * a1.sin());
+    let e = (m.0 + dx2, m.1 + l2 * (a1 + a2).cos(), m.2 + l2 * (a1 + a2).sin());
+    (m, e)
+}
+
+/// Slow chest rise: shoulders (and the neck, their midpoint) lift and widen a
+/// touch, arms and head riding along.
+pub fn apply_breathing(pose: &mut Pose, base: &Pose, t: f32) {
+    let b    = (1.0 - (t * TAU / BREATH_PERIOD).cos()) / 2.0; // 0 → 1 → 0
+    let lift = b * 2.5;
+    let wide = b * 0.8;
+    for (j, bj, dx) in [
+        (&mut pose.left_shoulder,  &base.left_shoulder,  -wide),
+        (&mut pose.left_elbow,     &base.left_elbow,     -wide),
+        (&mut pose.left_wrist,     &base.left_wrist,     -wide),
+        (&mut pose.right_shoulder, &base.right_shoulder,  wide),
+        (&mut pose.right_elbow,    &base.right_elbow,     wide),
+        (&mut pose.right_wrist,    &base.right_wrist,     wide),
+        (&mut pose.neck,           &base.neck,            0.0),
+        (&mut pose.head,           &base.head,            0.0),
+    ] {
+        j.set_xyz((bj.x + dx, bj.y - lift, bj.z));
+    }
+    pose.head_nod = base.head_nod - b * 2.0; // chin lifts slightly on the inhale
+}
+
+/// Right hand raised beside the head, forearm rocking side to side.
+pub fn apply_wave(pose: &mut Pose, base: &Pose, t: f32) {
+    *pose = base.clone();
+    let sh = base.right_shoulder.xyz();
+    let (l1, l2) = (dist(&base.right_shoulder, &base.right_elbow), dist(&base.right_elbow, &base.right_wrist));
+    // Upper arm out to the side and a little up and forward.
+    let (ux, uy, uz) = (0.85_f32, -0.35_f32, 0.15_f32);
+    let n = (ux * ux + uy * uy + uz * uz).sqrt();
+    let el = (sh.0 + l1 * ux / n, sh.1 + l1 * uy / n, sh.2 + l1 * uz / n);
+    let rock = (t * TAU / WAVE_PERIOD).sin() * 0.45;
+    pose.right_elbow.set_xyz(el);
+    pose.right_wrist.set_xyz((el.0 + l2 * rock.sin(), el.1 - l2 * rock.cos(), el.2));
+    pose.right_fingers.spread = 30.0;
+    pose.head_tilt = base.head_tilt + 4.0;
+}
+
+/// In-place walk: legs and arms swing in opposition, the swing knee folds,
+/// and the body rides up and down so the planted foot stays on the floor.
+pub fn apply_walk(pose: &mut Pose, base: &Pose, t: f32) {
+    *pose = base.clone();
+    let (s, c) = (t * TAU / WALK_PERIOD).sin_cos();
+    let hip = base.crotch.xyz();
+    // A leg is swinging while its angle grows: left when cos > 0, right when cos < 0.
+    let knee_l = 0.08 + 0.8 * c.max(0.0).powi(2);
+    let knee_r = 0.08 + 0.8 * (-c).max(0.0).powi(2);
+    let (kl, al) = swing_limb(hip, &base.crotch, &base.left_knee,  &base.left_ankle,   0.42 * s, -knee_l);
+    let (kr, ar) = swing_limb(hip, &base.crotch, &base.right_knee, &base.right_ankle, -0.42 * s, -knee_r);
+    pose.left_knee.set_xyz(kl);  pose.left_ankle.set_xyz(al);
+    pose.right_knee.set_xyz(kr); pose.right_ankle.set_xyz(ar);
+    // Arms counter-swing: left arm back while the left leg is forward.
+    let (el, wl) = swing_limb(base.left_shoulder.xyz(),  &base.left_shoulder,  &base.left_elbow,  &base.left_wrist,  -0.38 * s, 0.35);
+    let (er, wr) = swing_limb(base.right_shoulder.xyz(), &base.right_shoulder, &base.right_elbow, &base.right_wrist,  0.38 * s, 0.35);
+    pose.left_elbow.set_xyz(el);  pose.left_wrist.set_xyz(wl);
+    pose.right_elbow.set_xyz(er); pose.right_wrist.set_xyz(wr);
+    // Drop the whole figure back onto the base floor.
+    let floor = base.left_ankle.y.max(base.right_ankle.y);
+    pose.translate(0.0, floor - pose.left_ankle.y.max(pose.right_ankle.y), 0.0);
+}
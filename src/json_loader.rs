@@ -6,12 +6,29 @@
 // - Style libraries
 // - Options and settings
 //
+// Asset lookup: `<app dir>/assets/<name>` on disk first, then the compile-time
+// embedded copy — so a fresh install works and users can still customise libraries.
+// An override that fails to parse is reported (see `take_override_errors`) and the
+// embedded copy is used instead, so a typo on disk never takes the app down.
+//
 // 3D Coordinate Support:
 // The StickFigure struct now uses Vec<f32> to support both legacy 2D poses [x, y]
 // and new 3D poses [x, y, z]. The to_pose() method automatically handles both formats.
+// With "mirror_missing": true a figure may list only one side's limb points; the
+// other side is filled in by flipping X. An optional "head_right" (right ear) or
+// "head_top" (crown) point sets the head roll.
+// With the `mediapipe` feature, `mediapipe_item` converts a BlazePose landmark
+// export into the same stick-figure points, and `pose_from_csv` does the same
+// for a `joint,x,y,z` spreadsheet export.
+// Items may also carry "camera": {"yaw": 30, "pitch": -10} (degrees, optional
+// "radius") to set the 3D view when the preset is picked, and "tags": [...] for
+// the selector's tag filter (categorised libraries also tag by category name).
 
 use serde::Deserialize;
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct OptionsLibrary {
@@ -86,21 +103,43 @@ pub struct GenericLibrary {
 }
 
 impl GenericLibrary {
-    pub fn extract_items(&self) -> Vec<GenericItem> {
-        let parse  = |v: &serde_json::Value| serde_json::from_value::<GenericItem>(v.clone()).ok();
-        let from_arr = |a: &Vec<serde_json::Value>| a.iter().filter_map(parse).collect::<Vec<_>>();
+    /// Collect every preset item in the library. Entries that fail to parse are
+    /// skipped and described in `errors` (by id/term when one is present).
+    pub fn extract_items(&self, errors: &mut Vec<String>) -> Vec<GenericItem> {
+        let mut parse = |v: &serde_json::Value| match serde_json::from_value::<GenericItem>(v.clone()) {
+            Ok(item) => Some(item),
+            Err(e) => {
+                let label = v.get("id").or_else(|| v.get("term")).and_then(|s| s.as_str()).unwrap_or("<unnamed>");
+                errors.push(format!("skipped item \"{label}\": {e}"));
+                None
+            }
+        };
         let Some(obj) = self.data.as_object() else { return vec![] };
-        obj.values().flat_map(|value| {
+        let mut out = Vec::new();
+        for value in obj.values() {
             if let Some(arr) = value.as_array() {
-                from_arr(arr)
+                out.extend(arr.iter().filter_map(&mut parse));
             } else if let Some(cats) = value.as_object()
                 .and_then(|o| o.get("categories")).and_then(|c| c.as_array())
             {
-                cats.iter().filter_map(|c| c.as_object())
-                    .flat_map(|cat| cat.values().filter_map(|v| v.as_array()).flat_map(from_arr))
-                    .collect()
-            } else { vec![] }
-        }).collect()
+                for cat in cats.iter().filter_map(|c| c.as_object()) {
+                    // The category name doubles as a tag so grouped libraries
+                    // (poses) can be filtered without tagging every entry.
+                    let cat_name = cat.get("category").and_then(|c| c.as_str());
+                    let cat_tag = cat_name.map(str::to_lowercase);
+                    for arr in cat.values().filter_map(|v| v.as_array()) {
+                        out.extend(arr.iter().filter_map(&mut parse).map(|mut item| {
+                            if let Some(t) = &cat_tag {
+                                if !item.tags.contains(t) { item.tags.push(t.clone()); }
+                            }
+                            item.category = cat_name.map(str::to_string);
+                            item
+                        }));
+                    }
+                }
+            }
+        }
+        out
     }
 }
 
@@ -112,24 +151,58 @@ pub struct GenericItem {
     #[serde(default)] pub prompt: Option<String>,
     #[serde(default)] pub stick_figure: Option<StickFigure>,
     #[serde(default)] pub semantics: Option<Semantics>,
+    #[serde(default)] pub camera: Option<CameraHint>,
+    #[serde(default)] pub tags: Vec<String>,
+    /// Name of the `categories` entry the item was listed under, if any.
+    #[serde(skip)] pub category: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct StickFigure { 
-    pub points: HashMap<String, Vec<f32>>
+    pub points: HashMap<String, Vec<f32>>,
+    /// One-sided poses: fill an absent left_*/right_* point by flipping X of its twin.
+    #[serde(default)] pub mirror_missing: bool,
+}
+
+impl StickFigure {
+    /// `points`, plus mirrored twins for absent sides when `mirror_missing` is set.
+    fn resolved_points(&self) -> Cow<'_, HashMap<String, Vec<f32>>> {
+        if !self.mirror_missing { return Cow::Borrowed(&self.points); }
+        let mut pts = self.points.clone();
+        for (name, p) in &self.points {
+            let twin = if let Some(rest) = name.strip_prefix("left_") { format!("right_{rest}") }
+                       else if let Some(rest) = name.strip_prefix("right_") { format!("left_{rest}") }
+                       else { continue };
+            if self.points.contains_key(&twin) { continue; }
+            let mut m = p.clone();
+            if let Some(x) = m.first_mut() { *x = -*x; }
+            pts.insert(twin, m);
+        }
+        Cow::Owned(pts)
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct Semantics { pub prompt: String }
 
+/// Suggested 3D view for a preset pose, applied when it's selected.
+/// `yaw`/`pitch` are degrees; a missing `radius` keeps the current distance.
+#[derive(Debug, Deserialize, Clone, Copy)]
+pub struct CameraHint {
+    #[serde(default)] pub yaw: f32,
+    #[serde(default)] pub pitch: f32,
+    #[serde(default)] pub radius: Option<f32>,
+}
+
 impl GenericItem {
     pub fn to_pose(&self, cx: f32, cy: f32, scale: f32) -> Option<crate::pose::Pose> {
         let sf = self.stick_figure.as_ref()?;
         let sk = crate::skeleton::get();
+        let points = sf.resolved_points();
 
         // Helper to get point with smart Z defaults based on anatomy
         let pt = |name: &str| -> (f32, f32, f32) {
-            sf.points.get(name).map(|p| {
+            points.get(name).map(|p| {
                 let z = if p.len() >= 3 { 
                     p[2] * scale 
                 } else {
@@ -148,11 +221,15 @@ impl GenericItem {
         };
         let j = |name: &str| { let (x, y, z) = pt(name); crate::pose::Joint::new_3d(x, y, z) };
 
+        // Wrists/ankles hang straight down from elbow/knee unless the figure
+        // gives them explicitly (imported landmarks always do).
         let wrist = |elbow: &str| {
+            if points.contains_key(&elbow.replace("elbow", "wrist")) { return j(&elbow.replace("elbow", "wrist")); }
             let (ex, ey, ez) = pt(elbow);
             crate::pose::Joint::new_3d(ex, ey + sk.seg("forearm"), ez)
         };
         let ankle = |knee: &str| {
+            if points.contains_key(&knee.replace("knee", "ankle")) { return j(&knee.replace("knee", "ankle")); }
             let (kx, ky, kz) = pt(knee);
             crate::pose::Joint::new_3d(kx, ky + sk.seg("shin"), kz)
         };
@@ -240,10 +317,12 @@ impl GenericItem {
         let rwr = pose.right_wrist.xyz();
         pose.right_wrist.set_xyz(constrain_dist(rel, rwr, sk.seg("forearm")));
         
-        // Fix spine
+        // Fix spine. Figures don't author a waist, so it goes on the straight
+        // neck→pelvis line rather than hanging plumb below the neck, which
+        // would read as a rounded or arched back on any leaning figure.
         let neck = pose.neck.xyz();
-        let waist = pose.waist.xyz();
-        pose.waist.set_xyz(constrain_dist(neck, waist, sk.seg("torso_upper")));
+        let crotch = pose.crotch.xyz();
+        pose.waist.set_xyz(constrain_dist(neck, crotch, sk.seg("torso_upper")));
         let waist = pose.waist.xyz();
         let crotch = pose.crotch.xyz();
         pose.crotch.set_xyz(constrain_dist(waist, crotch, sk.seg("torso_lower")));
@@ -264,13 +343,14 @@ impl GenericItem {
         pose.right_ankle.set_xyz(constrain_dist(rkn, rank, sk.seg("shin")));
         
         // ── Derive head orientation from the neck→head direction vector ──────────────
-        // Coordinate space: X = right, Y = up, Z = into screen (away from viewer).
+        // Coordinate space: X = right, Y = up, +Z = the character's forward (toward the viewer).
         //
         //   head_nod  > 0  chin down  (head tips toward camera / looking forward-down)
         //             < 0  chin up    (head tips away  / looking up)
         //   head_yaw  > 0  turned right (character's own right)
         //             < 0  turned left
-        //   head_tilt = 0  roll — cannot be inferred from a 2-point vector alone
+        //   head_tilt > 0  ear toward the right shoulder — roll can't come from a
+        //                  2-point vector, so it needs a "head_right" or "head_top" hint
         {
             let (nx, ny, nz) = pose.neck.xyz();
             let (hx, hy, hz) = pose.head.xyz();
@@ -278,19 +358,27 @@ impl GenericItem {
             let len = (dx*dx + dy*dy + dz*dz).sqrt();
             if len > 0.001 {
                 // Nod: angle of the neck→head vector in the YZ plane relative to straight up.
-                // Negative Z (toward viewer) → chin drops forward → positive nod.
-                pose.head_nod = (-dz / len).asin().to_degrees();
+                // Positive Z (forward, toward the viewer) → chin drops forward → positive nod.
+                pose.head_nod = (dz / len).asin().to_degrees();
 
                 // Yaw: lateral deviation of the neck→head vector in the XZ plane.
                 // Positive X (character's right) → positive yaw.
                 // We use the full len so poses with simultaneous nod+yaw decode correctly.
                 pose.head_yaw = (dx / len).asin().to_degrees();
-
-                // Tilt (roll around the neck→head axis) cannot be resolved from
-                // two points — leave it neutral. A future pass could read a
-                // "head_right" hint from the JSON if you add one.
-                pose.head_tilt = 0.0;
             }
+            // Tilt: from a point on the right side of the head (right ear drops →
+            // tilted right) or above it (crown swings right → tilted right).
+            // Neutral when the figure gives neither. Measured against the JSON
+            // head point, since the refit above may have shifted pose.head.
+            let (hx, hy, hz) = pt("head");
+            pose.head_tilt = if points.contains_key("head_right") {
+                let (rx, ry, rz) = pt("head_right");
+                let (dx, dy, dz) = (rx - hx, ry - hy, rz - hz);
+                dy.atan2((dx*dx + dz*dz).sqrt()).to_degrees()
+            } else if points.contains_key("head_top") {
+                let (tx, ty, _) = pt("head_top");
+                (tx - hx).atan2(hy - ty).to_degrees()
+            } else { 0.0 };
         }
 
         Some(pose)
@@ -316,8 +404,118 @@ pub struct ComponentConfig {
     pub data_source: String,
 }
 
-// include_str! requires compile-time paths; all assets must be listed here.
-fn asset(name: &str) -> Result<&'static str, String> {
+/// MediaPipe / BlazePose landmark export (33 points, normalised [0,1] image
+/// coordinates, y down, z ≈ x scale with negative toward the camera) as a
+/// stick figure ready for `to_pose`. Accepts a bare landmark array, a
+/// `{"landmarks": [...]}` object, or a multi-person `[[...], ...]` (first person).
+///
+/// The photographed person faces the camera, as our figures face the Front
+/// camera at +Z: MediaPipe's z (negative toward the camera) is negated into
+/// our +Z forward, and x is mirrored so LEFT_* lands on our left_* at −X. Output units match poses.json: lower
+/// ankle at y = 0, head at ~7.2, pelvis centred on x = 0.
+#[cfg(feature = "mediapipe")]
+pub fn mediapipe_item(json: &str) -> Result<GenericItem, String> {
+    #[derive(Deserialize)]
+    struct Landmark { x: f32, y: f32, #[serde(default)] z: f32 }
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Export { Flat(Vec<Landmark>), Wrapped { landmarks: Vec<Landmark> }, Multi(Vec<Vec<Landmark>>) }
+
+    let lm = match serde_json::from_str::<Export>(json).map_err(|e| e.to_string())? {
+        Export::Flat(v) | Export::Wrapped { landmarks: v } => v,
+        Export::Multi(v) => v.into_iter().next().unwrap_or_default(),
+    };
+    if lm.len() < 33 { return Err(format!("expected 33 MediaPipe landmarks, found {}", lm.len())); }
+
+    // BlazePose indices for the joints we model.
+    const MAP: [(&str, usize); 13] = [
+        ("head", 0),
+        ("left_shoulder", 11), ("right_shoulder", 12), ("left_elbow", 13), ("right_elbow", 14),
+        ("left_wrist", 15),    ("right_wrist", 16),    ("left_hip", 23),   ("right_hip", 24),
+        ("left_knee", 25),     ("right_knee", 26),     ("left_ankle", 27), ("right_ankle", 28),
+    ];
+    let floor = lm[27].y.max(lm[28].y);
+    let height = (floor - lm[0].y).max(1e-3);
+    let k = 7.2 / height;
+    let mid_x = (lm[23].x + lm[24].x) / 2.0;
+    let conv = |l: &Landmark| vec![(mid_x - l.x) * k, (floor - l.y) * k, -l.z * k];
+
+    let mut points: HashMap<String, Vec<f32>> = MAP.iter()
+        .map(|&(name, i)| (name.to_string(), conv(&lm[i]))).collect();
+    let (lh, rh) = (&points["left_hip"], &points["right_hip"]);
+    let pelvis = (0..3).map(|i| (lh[i] + rh[i]) / 2.0).collect();
+    points.insert("pelvis".into(), pelvis);
+
+    Ok(GenericItem {
+        id: "mediapipe".into(), name: "MediaPipe import".into(), prompt: None, semantics: None, camera: None,
+        tags: vec![], category: None,
+        stick_figure: Some(StickFigure { points, mirror_missing: false }),
+    })
+}
+
+/// Stick-figure point names `to_pose` reads; `crotch` is accepted for `pelvis`.
+const CSV_JOINTS: [&str; 15] = [
+    "head", "head_right", "head_top", "neck",
+    "left_shoulder", "right_shoulder", "left_elbow", "right_elbow", "left_wrist", "right_wrist",
+    "pelvis", "left_knee", "right_knee", "left_ankle", "right_ankle",
+];
+
+/// Build a pose from CSV rows of `joint,x,y,z` in poses.json units (y up,
+/// z optional), through the same bone-length repair as a preset. A `joint,…`
+/// header, blank lines and `#` comments are skipped; unknown joint names and
+/// unparseable rows are skipped with a warning; missing joints get `to_pose`'s
+/// defaults. `None` when no row is usable.
+pub fn pose_from_csv(text: &str, cx: f32, cy: f32, scale: f32) -> Option<crate::pose::Pose> {
+    let mut points: HashMap<String, Vec<f32>> = HashMap::new();
+    for (n, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') { continue; }
+        let mut cols = line.split(',').map(str::trim);
+        let name = cols.next().unwrap_or_default().trim_matches('"').to_lowercase();
+        if n == 0 && name == "joint" { continue; }
+        let name = if name == "crotch" { "pelvis".to_string() } else { name };
+        if !CSV_JOINTS.contains(&name.as_str()) {
+            eprintln!("Warning: CSV line {}: unknown joint '{name}', skipped", n + 1);
+            continue;
+        }
+        let xyz: Result<Vec<f32>, _> = cols.filter(|c| !c.is_empty()).map(str::parse).collect();
+        match xyz {
+            // "nan" and "inf" parse as f32 but would poison every joint they touch.
+            Ok(v) if (2..=3).contains(&v.len()) && v.iter().all(|c| c.is_finite()) => { points.insert(name, v); }
+            _ => eprintln!("Warning: CSV line {}: expected joint,x,y[,z] as finite numbers, skipped", n + 1),
+        }
+    }
+    if points.is_empty() { return None; }
+    GenericItem {
+        id: "csv".into(), name: "CSV import".into(), prompt: None, semantics: None, camera: None,
+        tags: vec![], category: None,
+        stick_figure: Some(StickFigure { points, mirror_missing: false }),
+    }.to_pose(cx, cy, scale)
+}
+
+/// User override directory: `<app dir>/assets/`. Files here shadow the embedded
+/// copies by name, and new files (e.g. `my_poses.json`) can be referenced from an
+/// overridden `ui_config.json` without recompiling.
+pub fn override_dir() -> std::path::PathBuf { crate::app::get_app_dir().join("assets") }
+
+/// Off by default, so headless library use reads only the embedded assets and
+/// never creates the app directory; the GUI switches it on at startup.
+static USER_OVERRIDES: AtomicBool = AtomicBool::new(false);
+
+/// Let `load` read files from `override_dir()`. Must run before the first
+/// `skeleton::get()` / `semantics::config()`, which cache what they load.
+pub fn enable_user_overrides() { USER_OVERRIDES.store(true, Ordering::Relaxed); }
+
+/// Parse errors from override files that were skipped for the embedded copy.
+static OVERRIDE_ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Drain the override errors collected so far, for the load-error banner.
+pub fn take_override_errors() -> Vec<String> {
+    OVERRIDE_ERRORS.lock().map(|mut v| std::mem::take(&mut *v)).unwrap_or_default()
+}
+
+// include_str! requires compile-time paths; all built-in assets must be listed here.
+fn embedded(name: &str) -> Result<&'static str, String> {
     match name {
         "ui_config.json"             => Ok(include_str!("../assets/ui_config.json")),
         "character_attributes.json"  => Ok(include_str!("../assets/character_attributes.json")),
@@ -327,14 +525,47 @@ fn asset(name: &str) -> Result<&'static str, String> {
         "global.json"                => Ok(include_str!("../assets/global.json")),
         "poses.json"                 => Ok(include_str!("../assets/poses.json")),
         "expressions.json"           => Ok(include_str!("../assets/expressions.json")),
+        "props.json"                 => Ok(include_str!("../assets/props.json")),
         "environments.json"          => Ok(include_str!("../assets/environments.json")),
         "skeleton.json"              => Ok(include_str!("../assets/skeleton.json")),
+        "semantics_thresholds.json"  => Ok(include_str!("../assets/semantics_thresholds.json")),
         _ => Err(format!("Asset '{name}' not embedded. Add it to json_loader.rs asset() to embed at compile time.")),
     }
 }
 
 pub fn load<T: for<'de> Deserialize<'de>>(name: &str) -> Result<T, String> {
-    serde_json::from_str(asset(name)?).map_err(|e| format!("Parse error in {name}: {e}"))
+    if !USER_OVERRIDES.load(Ordering::Relaxed) {
+        return serde_json::from_str(embedded(name)?).map_err(|e| describe_error(name, &e));
+    }
+    let path = override_dir().join(name);
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        let text = embedded(name).map_err(|e| format!("{e} (no override at {})", path.display()))?;
+        return serde_json::from_str(text).map_err(|e| describe_error(name, &e));
+    };
+    let err = match serde_json::from_str(&text) {
+        Ok(v) => return Ok(v),
+        Err(e) => describe_error(name, &e),
+    };
+    // A user-added file has nothing to fall back to.
+    let Ok(text) = embedded(name) else { return Err(err) };
+    let v = serde_json::from_str(text).map_err(|e| describe_error(name, &e))?;
+    let err = format!("{err} — using the built-in copy");
+    eprintln!("Warning: {err}");
+    if let Ok(mut errors) = OVERRIDE_ERRORS.lock() { errors.push(err); }
+    Ok(v)
+}
+
+/// "poses.json: missing field `id` (line 12, column 5) [data]" — serde already names
+/// the offending field; we move the position up front and tag the error class.
+fn describe_error(name: &str, e: &serde_json::Error) -> String {
+    use serde_json::error::Category;
+    let kind = match e.classify() {
+        Category::Syntax => "syntax", Category::Data => "data",
+        Category::Eof    => "unexpected end of file", Category::Io => "io",
+    };
+    let msg = e.to_string();
+    let msg = msg.split(" at line ").next().unwrap_or(&msg);
+    format!("{name}: {msg} (line {}, column {}) [{kind}]", e.line(), e.column())
 }
 
 impl OptionCategory {
@@ -0,0 +1,54 @@
+// lib.rs
+// PromptPuppet as a library: the GUI binary (main.rs) is a thin shell over these
+// modules, and the pose → text engine can be used headless by other tools.
+// Headless callers get the embedded assets only; user overrides in the app
+// directory are switched on by the GUI (`json_loader::enable_user_overrides`).
+
+mod app;
+mod ftlz;
+pub mod pose;
+mod prompt;
+pub mod skeleton;
+pub mod semantics;
+mod ui_panels;
+pub mod json_loader;
+mod canvas3d;
+
+pub use app::PromptPuppetApp;
+
+use std::collections::HashMap;
+
+/// Build a `Pose` from raw joint positions, repaired to skeleton.json bone lengths.
+///
+/// Points use the poses.json `stick_figure` convention: `[x, y, z]` in skeleton
+/// units, Y up, X negative = character's left, with `"pelvis"` for the hip
+/// centre. Wrists and ankles are derived from elbows and knees; missing joints
+/// collapse to the origin, so supply at least head, neck, shoulders, elbows,
+/// pelvis and knees.
+pub fn pose_from_points(points: &HashMap<String, [f32; 3]>) -> Option<pose::Pose> {
+    let item = json_loader::GenericItem {
+        id: String::new(), name: String::new(), prompt: None, semantics: None, camera: None,
+        tags: vec![], category: None,
+        stick_figure: Some(json_loader::StickFigure {
+            points: points.iter().map(|(k, v)| (k.clone(), v.to_vec())).collect(),
+            mirror_missing: false,
+        }),
+    };
+    item.to_pose(400.0, 539.0, 40.0)
+}
+
+/// Natural-language description of a pose given as raw joint positions
+/// (see `pose_from_points` for the coordinate convention).
+pub fn describe_joints(points: &HashMap<String, [f32; 3]>) -> String {
+    pose_from_points(points).map_or_else(String::new, |p| {
+        semantics::describe(&p, &semantics::DescribeOptions::default(), semantics::config())
+    })
+}
+
+/// `describe_joints` as separate fields (stance, torso, head, arms, legs, …);
+/// serialise it with serde_json for a structured export.
+pub fn describe_joints_structured(points: &HashMap<String, [f32; 3]>) -> Option<semantics::PoseDescription> {
+    pose_from_points(points).map(|p| {
+        semantics::describe_structured(&p, &semantics::DescribeOptions::default(), semantics::config())
+    })
+}
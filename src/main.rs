@@ -1,17 +1,8 @@
 // main.rs
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-mod app;
-mod ftlz;
-mod pose;
-mod prompt;
-mod skeleton;
-mod semantics;
-mod ui_panels;
-mod json_loader;
-mod canvas3d;
-
 use eframe::egui;
+use prompt_puppet::PromptPuppetApp;
 
 fn main() -> Result<(), eframe::Error> {
     let icon_data = {
@@ -52,7 +43,7 @@ fn main() -> Result<(), eframe::Error> {
             fonts.families.get_mut(&egui::FontFamily::Monospace).unwrap()
                 .push("noto_emoji".to_owned());
             cc.egui_ctx.set_fonts(fonts);
-            Ok(Box::new(app::PromptPuppetApp::new(cc)))
+            Ok(Box::new(PromptPuppetApp::new(cc)))
         }),
     )
 }
\ No newline at end of file
@@ -10,12 +10,28 @@ impl Vec3 {
     fn from_tuple(t: (f32, f32, f32)) -> Self { Self::new(t.0, t.1, t.2) }
 
     fn dot(self, o: Self) -> f32 { self.x*o.x + self.y*o.y + self.z*o.z }
+    fn cross(self, o: Self) -> Self {
+        Self::new(self.y*o.z - self.z*o.y, self.z*o.x - self.x*o.z, self.x*o.y - self.y*o.x)
+    }
     fn len(self) -> f32 { self.dot(self).sqrt() }
     fn sub(self, o: Self) -> Self { Self::new(self.x-o.x, self.y-o.y, self.z-o.z) }
     fn distance(self, o: Self) -> f32 { self.sub(o).len() }
 }
 // ========== End Vec3 helpers ==========
 
+/// xorshift64* — tiny deterministic RNG so random poses are reproducible from a seed
+/// without pulling in an external crate.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self { Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1) }
+    fn next_f32(&mut self) -> f32 {
+        self.0 ^= self.0 >> 12; self.0 ^= self.0 << 25; self.0 ^= self.0 >> 27;
+        (self.0.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 40) as f32 / (1u64 << 24) as f32
+    }
+    fn range(&mut self, lo: f32, hi: f32) -> f32 { lo + (hi - lo) * self.next_f32() }
+}
+
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct Joint {
@@ -42,6 +58,11 @@ impl Joint {
     pub fn translate(&mut self, dx: f32, dy: f32, dz: f32) {
         self.x += dx; self.y += dy; self.z += dz;
     }
+
+    pub fn lerp(&self, o: &Self, t: f32) -> Self {
+        let l = |a: f32, b: f32| a + (b - a) * t;
+        Self { x: l(self.x, o.x), y: l(self.y, o.y), z: l(self.z, o.z), angle: l(self.angle, o.angle) }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -61,6 +82,14 @@ impl Default for FingerSet {
     fn default() -> Self { Self { thumb: 0.0, index: 0.0, middle: 0.0, ring: 0.0, pinky: 0.0, spread: 20.0 } }
 }
 
+impl FingerSet {
+    pub fn lerp(&self, o: &Self, t: f32) -> Self {
+        let l = |a: f32, b: f32| a + (b - a) * t;
+        Self { thumb: l(self.thumb, o.thumb), index: l(self.index, o.index), middle: l(self.middle, o.middle),
+               ring: l(self.ring, o.ring), pinky: l(self.pinky, o.pinky), spread: l(self.spread, o.spread) }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Pose {
     pub head: Joint, pub neck: Joint,
@@ -93,7 +122,220 @@ impl std::hash::Hash for Pose {
     }
 }
 
+/// Canonical joint order shared by every consumer that walks all joints.
+/// Matches the skeleton.json joint names; adding a joint means editing this
+/// list and the two arrays in `joints_ordered`/`joints_ordered_mut`.
+pub const JOINT_NAMES: [&str; 14] = [
+    "head", "neck",
+    "left_shoulder", "right_shoulder",
+    "left_elbow",    "right_elbow",
+    "left_wrist",    "right_wrist",
+    "waist",         "crotch",
+    "left_knee",     "right_knee",
+    "left_ankle",    "right_ankle",
+];
+
 impl Pose {
+    /// Every joint with its name, in `JOINT_NAMES` order.
+    pub fn joints_ordered(&self) -> impl Iterator<Item = (&'static str, &Joint)> {
+        JOINT_NAMES.into_iter().zip([
+            &self.head, &self.neck,
+            &self.left_shoulder,  &self.right_shoulder,
+            &self.left_elbow,     &self.right_elbow,
+            &self.left_wrist,     &self.right_wrist,
+            &self.waist,          &self.crotch,
+            &self.left_knee,      &self.right_knee,
+            &self.left_ankle,     &self.right_ankle,
+        ])
+    }
+
+    /// Mutable counterpart of `joints_ordered`, same order.
+    pub fn joints_ordered_mut(&mut self) -> impl Iterator<Item = (&'static str, &mut Joint)> {
+        JOINT_NAMES.into_iter().zip([
+            &mut self.head, &mut self.neck,
+            &mut self.left_shoulder,  &mut self.right_shoulder,
+            &mut self.left_elbow,     &mut self.right_elbow,
+            &mut self.left_wrist,     &mut self.right_wrist,
+            &mut self.waist,          &mut self.crotch,
+            &mut self.left_knee,      &mut self.right_knee,
+            &mut self.left_ankle,     &mut self.right_ankle,
+        ])
+    }
+
+    /// Look up a joint by skeleton name.
+    pub fn joint(&self, name: &str) -> Option<&Joint> {
+        self.joints_ordered().find(|(n, _)| *n == name).map(|(_, j)| j)
+    }
+
+    /// Upright neutral pose built from skeleton.json segment lengths (arms
+    /// hanging, feet under the hips, crotch at the origin, Y down), with the
+    /// named joints then moved to the given positions. No bone-length repair
+    /// runs afterwards, so deliberately odd geometry stays as given — meant for
+    /// building classifier cases in code. Unknown names are ignored. head_nod
+    /// and head_yaw are then read off the neck→head vector, as on load; roll
+    /// can't come from two points, so set `head_tilt` by hand if it matters.
+    pub fn from_points(points: &[(&str, (f32, f32, f32))]) -> Pose {
+        let sk = crate::skeleton::get();
+        let (h, s) = (sk.head_size, &sk.segments);
+        let j = |x: f32, y: f32| Joint::new_3d(x * h, y * h, 0.0);
+        let (waist_y, neck_y) = (-s.torso_lower, -(s.torso_lower + s.torso_upper));
+        let sh = s.shoulder_width / 2.0;
+        let mut pose = Pose {
+            head: j(0.0, neck_y - s.neck), neck: j(0.0, neck_y),
+            left_shoulder: j(-sh, neck_y),  right_shoulder: j(sh, neck_y),
+            left_elbow:    j(-sh, neck_y + s.arm), right_elbow: j(sh, neck_y + s.arm),
+            left_wrist:    j(-sh, neck_y + s.arm + s.forearm), right_wrist: j(sh, neck_y + s.arm + s.forearm),
+            left_fingers: FingerSet::default(), right_fingers: FingerSet::default(),
+            waist: j(0.0, waist_y), crotch: j(0.0, 0.0),
+            torso_lean: 0.0, torso_sway: 0.0,
+            left_knee:  j(-0.4, s.thigh), right_knee:  j(0.4, s.thigh),
+            left_ankle: j(-0.4, s.thigh + s.shin), right_ankle: j(0.4, s.thigh + s.shin),
+            head_tilt: 0.0, head_nod: 0.0, head_yaw: 0.0,
+        };
+        for &(name, xyz) in points {
+            if let Some((_, joint)) = pose.joints_ordered_mut().find(|(n, _)| *n == name) {
+                joint.set_xyz(xyz);
+            }
+        }
+        pose.sync_head_angles();
+        pose
+    }
+
+    /// Straight per-joint interpolation between two keyframes (t = 0 → self, 1 → other).
+    /// Bone lengths are not re-enforced; intermediate frames may shorten slightly
+    /// when a limb swings through a wide arc.
+    pub fn lerp(&self, o: &Pose, t: f32) -> Pose {
+        let l = |a: f32, b: f32| a + (b - a) * t;
+        Pose {
+            head: self.head.lerp(&o.head, t), neck: self.neck.lerp(&o.neck, t),
+            left_shoulder:  self.left_shoulder.lerp(&o.left_shoulder, t),
+            right_shoulder: self.right_shoulder.lerp(&o.right_shoulder, t),
+            left_elbow:     self.left_elbow.lerp(&o.left_elbow, t),
+            right_elbow:    self.right_elbow.lerp(&o.right_elbow, t),
+            left_wrist:     self.left_wrist.lerp(&o.left_wrist, t),
+            right_wrist:    self.right_wrist.lerp(&o.right_wrist, t),
+            left_fingers:   self.left_fingers.lerp(&o.left_fingers, t),
+            right_fingers:  self.right_fingers.lerp(&o.right_fingers, t),
+            waist: self.waist.lerp(&o.waist, t), crotch: self.crotch.lerp(&o.crotch, t),
+            torso_lean: l(self.torso_lean, o.torso_lean), torso_sway: l(self.torso_sway, o.torso_sway),
+            left_knee:   self.left_knee.lerp(&o.left_knee, t),
+            right_knee:  self.right_knee.lerp(&o.right_knee, t),
+            left_ankle:  self.left_ankle.lerp(&o.left_ankle, t),
+            right_ankle: self.right_ankle.lerp(&o.right_ankle, t),
+            head_tilt: l(self.head_tilt, o.head_tilt), head_nod: l(self.head_nod, o.head_nod),
+            head_yaw:  l(self.head_yaw, o.head_yaw),
+        }
+    }
+
+    /// Random but anatomically plausible pose, reproducible from `seed`.
+    ///
+    /// Built forward from the crotch with exact skeleton.json bone lengths, so no
+    /// repair pass is needed. Elbow/knee bends respect the skeleton constraints;
+    /// limb swings, torso lean/twist and head orientation stay within comfortable
+    /// ranges. The figure is centred on `cx` with the lower ankle resting on `cy`.
+    pub fn random(cx: f32, cy: f32, seed: u64, sk: &crate::skeleton::Skeleton) -> Pose {
+        let mut rng = Rng::new(seed);
+        let norm = |v: (f32,f32,f32)| { let m = (v.0*v.0+v.1*v.1+v.2*v.2).sqrt().max(1e-6); (v.0/m, v.1/m, v.2/m) };
+        let add  = |a: (f32,f32,f32), d: (f32,f32,f32), l: f32| (a.0+d.0*l, a.1+d.1*l, a.2+d.2*l);
+        // Limb direction from a downward-hanging rest: `swing` toward +Z (forward),
+        // then `abduct` outward on `sign`'s side. Degrees. Y is down.
+        let limb = |swing: f32, abduct: f32, sign: f32| {
+            let (st, ct) = swing.to_radians().sin_cos();
+            let (sp, cp) = abduct.to_radians().sin_cos();
+            (sign * sp, cp * ct, cp * st)
+        };
+        // Bend `u` by `flex` degrees toward `hint` (elbows fold forward, knees back).
+        let bend = |u: (f32,f32,f32), flex: f32, hint: (f32,f32,f32)| {
+            let d = hint.0*u.0 + hint.1*u.1 + hint.2*u.2;
+            let mut v = (hint.0-d*u.0, hint.1-d*u.1, hint.2-d*u.2);
+            if (v.0*v.0+v.1*v.1+v.2*v.2) < 1e-6 { v = (0.0, -1.0, 0.0); }
+            let v = norm(v);
+            let (sf, cf) = flex.to_radians().sin_cos();
+            norm((u.0*cf+v.0*sf, u.1*cf+v.1*sf, u.2*cf+v.2*sf))
+        };
+        let c = &sk.constraints;
+
+        // ── Torso: lean forward/back, side tilt, twist of the shoulder bar ───
+        let (lean, side, twist) = (rng.range(-12.0, 25.0), rng.range(-12.0, 12.0), rng.range(-30.0, 30.0));
+        let (sa, ca) = lean.to_radians().sin_cos();
+        let (sb, cb) = side.to_radians().sin_cos();
+        // torso_lean() reads a neck displaced toward +Z as leaning forward.
+        let spine = (sb, -cb*ca, cb*sa);
+        let crotch = (0.0, 0.0, 0.0);
+        let waist  = add(crotch, spine, sk.seg("torso_lower"));
+        let neck   = add(waist,  spine, sk.seg("torso_upper"));
+        let (sw, cw) = twist.to_radians().sin_cos();
+        let bar = (cw, 0.0, -sw);
+        let half = sk.seg("shoulder_width") / 2.0;
+        let l_sh = add(neck, bar, -half);
+        let r_sh = add(neck, bar,  half);
+
+        // ── Arms: anywhere from hanging to overhead, elbow within constraints ─
+        let mut arm = |sh: (f32,f32,f32), sign: f32| {
+            let u  = limb(rng.range(-30.0, 170.0), rng.range(0.0, 80.0), sign);
+            let el = add(sh, u, sk.seg("arm"));
+            let flex = 180.0 - rng.range(c.elbow.min.max(45.0), c.elbow.max);
+            (el, add(el, bend(u, flex, (0.0, 0.0, 1.0)), sk.seg("forearm")))
+        };
+        let (l_el, l_wr) = arm(l_sh, -1.0);
+        let (r_el, r_wr) = arm(r_sh,  1.0);
+
+        // ── Legs: mostly weight-bearing; one may lift a little higher ────────
+        let lift       = rng.next_f32() < 0.3;
+        let raise_left = rng.next_f32() < 0.5;
+        let mut leg = |sign: f32, raised: bool| {
+            let swing = if raised { rng.range(-20.0, 70.0) } else { rng.range(-20.0, 30.0) };
+            let u  = limb(swing, rng.range(0.0, 25.0), sign);
+            let kn = add(crotch, u, sk.seg("thigh"));
+            let lo = if raised { c.knee.min.max(70.0) } else { c.knee.min.max(140.0) };
+            let flex = 180.0 - rng.range(lo, c.knee.max);
+            (kn, add(kn, bend(u, flex, (0.0, 0.0, -1.0)), sk.seg("shin")))
+        };
+        let (l_kn, l_an) = leg(-1.0, lift && raise_left);
+        let (r_kn, r_an) = leg( 1.0, lift && !raise_left);
+
+        // ── Head: nod / yaw / roll in the same convention as head_orient() ───
+        let (nod, yaw, tilt) = (rng.range(-20.0, 25.0), rng.range(-40.0, 40.0), rng.range(-12.0, 12.0));
+        let (sy, sn) = (yaw.to_radians().sin(), nod.to_radians().sin());
+        let up = (1.0 - sy*sy - sn*sn).max(0.0).sqrt();
+        let head = add(neck, (sy, -up, sn), sk.seg("neck"));
+
+        let mut fingers = || FingerSet {
+            thumb: rng.range(0.0, 60.0), index: rng.range(0.0, 60.0), middle: rng.range(0.0, 60.0),
+            ring: rng.range(0.0, 60.0), pinky: rng.range(0.0, 60.0), spread: rng.range(5.0, 35.0),
+        };
+        let (left_fingers, right_fingers) = (fingers(), fingers());
+
+        // ── Place: crotch over cx, lower ankle on the floor at cy ────────────
+        let (dx, dy) = (cx, cy - l_an.1.max(r_an.1));
+        let j = |p: (f32,f32,f32)| Joint::new_3d(p.0 + dx, p.1 + dy, p.2);
+        let mut pose = Pose {
+            head: j(head), neck: j(neck),
+            left_shoulder: j(l_sh), right_shoulder: j(r_sh),
+            left_elbow: j(l_el),    right_elbow: j(r_el),
+            left_wrist: j(l_wr),    right_wrist: j(r_wr),
+            left_fingers, right_fingers,
+            waist: j(waist), crotch: j(crotch),
+            torso_lean: lean, torso_sway: side,
+            left_knee: j(l_kn),   right_knee: j(r_kn),
+            left_ankle: j(l_an),  right_ankle: j(r_an),
+            head_tilt: tilt, head_nod: nod, head_yaw: yaw,
+        };
+        // Hands can still land inside the chest; the drag-release pass fixes
+        // that, and `validate` holds because every limb angle was drawn in range.
+        pose.resolve_self_collision(sk);
+        debug_assert!(pose.validate(sk).is_ok(), "{:?}", pose.validate(sk));
+        pose
+    }
+
+    /// Sample a keyframe sequence at a fractional index (1.5 = halfway between #2 and #3).
+    pub fn sample(keyframes: &[Pose], pos: f32) -> Option<Pose> {
+        let last = keyframes.len().checked_sub(1)?;
+        let pos  = pos.clamp(0.0, last as f32);
+        let i    = (pos.floor() as usize).min(last);
+        Some(keyframes[i].lerp(&keyframes[(i + 1).min(last)], pos - i as f32))
+    }
+
     /// Move a joint, maintaining bone lengths via FABRIK.
     /// No angle constraints — pose freely; semantics handles interpretation.
     pub fn move_joint(&mut self, name: &str, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton) {
@@ -103,6 +345,7 @@ impl Pose {
             }
             "head" => {
                 self.head.set_xyz(Self::fix_dist(self.neck.xyz(), target, sk.seg("neck")));
+                self.sync_head_angles();
             }
             "left_shoulder"  => self.move_shoulder("left",  target, sk),
             "right_shoulder" => self.move_shoulder("right", target, sk),
@@ -135,20 +378,401 @@ impl Pose {
         self.clamp_to_floor();
     }
 
+    /// `move_joint` with the pelvis pinned: a neck or waist drag solves the
+    /// spine from the crotch as the fixed FABRIK root, so the spine bends and
+    /// the crotch, legs and planted feet stay where they are. The head,
+    /// shoulders and arms ride along with the neck. Other joints behave as in
+    /// `move_joint`.
+    pub fn move_joint_pinned(&mut self, name: &str, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton) {
+        let idx = match name {
+            "neck"  => 2,
+            "waist" => 1,
+            _ => return self.move_joint(name, target, sk),
+        };
+        let old_neck = self.neck.xyz();
+        let mut chain = [self.crotch.xyz(), self.waist.xyz(), self.neck.xyz()];
+        Self::fabrik_solve(&mut chain, &[sk.seg("torso_lower"), sk.seg("torso_upper")], target, idx, &sk.solver);
+        // chain[0] (crotch) is fixed root — don't write back
+        self.waist.set_xyz(chain[1]);
+        self.neck.set_xyz(chain[2]);
+        let nd = (chain[2].0-old_neck.0, chain[2].1-old_neck.1, chain[2].2-old_neck.2);
+        self.head.translate(nd.0, nd.1, nd.2);
+        self.left_shoulder.translate(nd.0, nd.1, nd.2);
+        self.right_shoulder.translate(nd.0, nd.1, nd.2);
+        self.drag_arm("left",  nd.0, nd.1, nd.2);
+        self.drag_arm("right", nd.0, nd.1, nd.2);
+        self.clamp_to_floor();
+    }
+
+    /// Forward-kinematics drag: swing the bone ending at `name` about its
+    /// parent joint toward `target` and carry everything below it rigidly, so
+    /// dragging an elbow rotates the whole arm at the shoulder without
+    /// changing the elbow bend. Torso and head joints have no FK chain and
+    /// fall back to `move_joint`.
+    pub fn rotate_joint(&mut self, name: &str, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton) {
+        let chain: &[&str] = match name {
+            "left_elbow"  => &["left_shoulder",  "left_elbow",  "left_wrist"],
+            "right_elbow" => &["right_shoulder", "right_elbow", "right_wrist"],
+            "left_wrist"  => &["left_elbow",  "left_wrist"],
+            "right_wrist" => &["right_elbow", "right_wrist"],
+            "left_knee"   => &["crotch", "left_knee",  "left_ankle"],
+            "right_knee"  => &["crotch", "right_knee", "right_ankle"],
+            "left_ankle"  => &["left_knee",  "left_ankle"],
+            "right_ankle" => &["right_knee", "right_ankle"],
+            _ => return self.move_joint(name, target, sk),
+        };
+        let (Some(pivot), Some(end)) = (self.joint(chain[0]), self.joint(chain[1])) else { return };
+        let pivot = Vec3::from_tuple(pivot.xyz());
+        let (a, b) = (Vec3::from_tuple(end.xyz()).sub(pivot), Vec3::from_tuple(target).sub(pivot));
+        let (la, lb) = (a.len(), b.len());
+        if la < 1e-3 || lb < 1e-3 { return; }
+        // Rodrigues rotation taking the bone direction onto the target direction.
+        let axis = Vec3::new(a.x/la, a.y/la, a.z/la).cross(Vec3::new(b.x/lb, b.y/lb, b.z/lb));
+        let sin = axis.len();
+        if sin < 1e-6 { return; }
+        let cos = a.dot(b) / (la * lb);
+        let k = Vec3::new(axis.x/sin, axis.y/sin, axis.z/sin);
+        let rotate = |v: Vec3| {
+            let (kv, kd) = (k.cross(v), k.dot(v) * (1.0 - cos));
+            Vec3::new(v.x*cos + kv.x*sin + k.x*kd, v.y*cos + kv.y*sin + k.y*kd, v.z*cos + kv.z*sin + k.z*kd)
+        };
+        for (n, j) in self.joints_ordered_mut() {
+            if !chain[1..].contains(&n) { continue; }
+            let v = rotate(Vec3::from_tuple(j.xyz()).sub(pivot));
+            j.set_xyz((pivot.x + v.x, pivot.y + v.y, pivot.z + v.z));
+        }
+        self.clamp_to_floor();
+    }
+
+    /// Exaggerate (`factor` > 1) or tone down (< 1) this pose relative to `base`
+    /// by extrapolating every joint and angle along base → self, then refitting
+    /// bone lengths. The crotch stays put in X/Z and the lower ankle stays on
+    /// this pose's floor, so the figure doesn't drift while dialling.
+    pub fn scale_from(&self, base: &Pose, factor: f32, sk: &crate::skeleton::Skeleton) -> Pose {
+        let mut out = base.lerp(self, factor);
+        out.refit_from_neck(sk);
+        let floor = self.left_ankle.y.max(self.right_ankle.y);
+        let out_floor = out.left_ankle.y.max(out.right_ankle.y);
+        out.translate(self.crotch.x - out.crotch.x, floor - out_floor, self.crotch.z - out.crotch.z);
+        out.clamp_to_floor();
+        out
+    }
+
+    /// Coarse "what changed since `base`" list, e.g. `["right arm moved significantly",
+    /// "head turned"]`. Joints are compared relative to the crotch so moving the
+    /// whole figure reads as one entry instead of flagging every limb; thresholds
+    /// are fractions of `base`'s body height.
+    pub fn diff_summary(&self, base: &Pose) -> Vec<String> {
+        let h = (base.left_ankle.y.max(base.right_ankle.y) - base.head.y).abs().max(1.0);
+        let rel = |p: &Pose, j: &Joint| Vec3::new(j.x - p.crotch.x, j.y - p.crotch.y, j.z - p.crotch.z);
+        let moved = |names: &[&str]| names.iter()
+            .filter_map(|n| Some(rel(self, self.joint(n)?).distance(rel(base, base.joint(n)?))))
+            .fold(0.0_f32, f32::max) / h;
+        let mut out = Vec::new();
+        if Vec3::from_tuple(self.crotch.xyz()).distance(Vec3::from_tuple(base.crotch.xyz())) / h > 0.05 {
+            out.push("whole figure moved".to_string());
+        }
+        for (part, names) in [
+            ("torso",     &["neck", "waist", "left_shoulder", "right_shoulder"][..]),
+            ("left arm",  &["left_elbow", "left_wrist"][..]),
+            ("right arm", &["right_elbow", "right_wrist"][..]),
+            ("left leg",  &["left_knee", "left_ankle"][..]),
+            ("right leg", &["right_knee", "right_ankle"][..]),
+        ] {
+            let d = moved(names);
+            if d > 0.15 { out.push(format!("{part} moved significantly")); }
+            else if d > 0.04 { out.push(format!("{part} moved slightly")); }
+        }
+        // Head position follows the neck; its orientation is what the user changes.
+        for (label, a, b) in [
+            ("head turned", self.head_yaw,  base.head_yaw),
+            ("head nodded", self.head_nod,  base.head_nod),
+            ("head tilted", self.head_tilt, base.head_tilt),
+        ] {
+            if (a - b).abs() > 8.0 { out.push(label.to_string()); }
+        }
+        let curl = |f: &FingerSet| [f.thumb, f.index, f.middle, f.ring, f.pinky, f.spread];
+        for (side, a, b) in [("left", &self.left_fingers, &base.left_fingers),
+                             ("right", &self.right_fingers, &base.right_fingers)] {
+            if curl(a).iter().zip(curl(b)).any(|(x, y)| (x - y).abs() > 10.0) {
+                out.push(format!("{side} hand changed"));
+            }
+        }
+        out
+    }
+
+    /// Soft check for poses an image model is likely to garble: a knee folded
+    /// flat onto itself, a head rolled far over onto a shoulder, or one turned
+    /// more than 90° past the shoulders. Nothing is corrected —
+    /// each entry is a short human-readable note for the prompt panel.
+    pub fn plausibility_warnings(&self) -> Vec<String> {
+        let v = |j: &Joint| Vec3::from_tuple(j.xyz());
+        let angle = |a: &Joint, b: &Joint, c: &Joint| {
+            let (u, w) = (v(a).sub(v(b)), v(c).sub(v(b)));
+            (u.dot(w) / (u.len() * w.len()).max(1e-6)).clamp(-1.0, 1.0).acos().to_degrees()
+        };
+        let mut out = Vec::new();
+        // Elbows really do fold until the hand meets the shoulder, and several
+        // presets rely on it; a knee folded tighter than a deep kneel does not.
+        for (what, knee, ankle) in [("left knee",  &self.left_knee,  &self.left_ankle),
+                                    ("right knee", &self.right_knee, &self.right_ankle)] {
+            let deg = angle(&self.crotch, knee, ankle);
+            if deg < 10.0 { out.push(format!("{what} folded flat ({deg:.0}°)")); }
+        }
+        if self.head_tilt.abs() > 50.0 {
+            out.push(format!("head rolled {:.0}° toward the shoulder", self.head_tilt.abs()));
+        }
+        // Shoulder-bar yaw with the same sign as `head_yaw` (+ = character's right).
+        let body_yaw = (self.left_shoulder.z - self.right_shoulder.z)
+            .atan2((self.left_shoulder.x - self.right_shoulder.x).abs().max(1.0)).to_degrees();
+        let neck_turn = self.head_yaw - body_yaw;
+        if neck_turn.abs() > 90.0 {
+            out.push(format!("head turned {:.0}° past the shoulders", neck_turn.abs()));
+        }
+        out
+    }
+
+    /// Hard check that the pose is one the skeleton can make: finite
+    /// coordinates, every skeleton.json bone length within 1%, the shoulder bar
+    /// centred on the neck, and elbow/knee angles inside `constraints`.
+    /// Returns the first violation found.
+    pub fn validate(&self, sk: &crate::skeleton::Skeleton) -> Result<(), String> {
+        if let Some((name, _)) = self.joints_ordered()
+            .find(|(_, j)| !(j.x.is_finite() && j.y.is_finite() && j.z.is_finite())) {
+            return Err(format!("{name} has a non-finite coordinate"));
+        }
+        let v = |j: &Joint| Vec3::from_tuple(j.xyz());
+        let bones = [
+            ("neck",        &self.neck,           &self.head,        "neck"),
+            ("left arm",    &self.left_shoulder,  &self.left_elbow,  "arm"),
+            ("left forearm",  &self.left_elbow,   &self.left_wrist,  "forearm"),
+            ("right arm",   &self.right_shoulder, &self.right_elbow, "arm"),
+            ("right forearm", &self.right_elbow,  &self.right_wrist, "forearm"),
+            ("upper torso", &self.neck,           &self.waist,       "torso_upper"),
+            ("lower torso", &self.waist,          &self.crotch,      "torso_lower"),
+            ("left thigh",  &self.crotch,         &self.left_knee,   "thigh"),
+            ("left shin",   &self.left_knee,      &self.left_ankle,  "shin"),
+            ("right thigh", &self.crotch,         &self.right_knee,  "thigh"),
+            ("right shin",  &self.right_knee,     &self.right_ankle, "shin"),
+            ("shoulders",   &self.left_shoulder,  &self.right_shoulder, "shoulder_width"),
+        ];
+        for (what, a, b, seg) in bones {
+            let (len, want) = (v(a).distance(v(b)), sk.seg(seg));
+            if (len - want).abs() > want * 0.01 {
+                return Err(format!("{what} is {len:.1}px, skeleton says {want:.1}px"));
+            }
+        }
+        let mid = Vec3::new((self.left_shoulder.x + self.right_shoulder.x) / 2.0,
+                            (self.left_shoulder.y + self.right_shoulder.y) / 2.0,
+                            (self.left_shoulder.z + self.right_shoulder.z) / 2.0);
+        if v(&self.neck).distance(mid) > sk.seg("shoulder_width") * 0.01 {
+            return Err("neck is off the shoulder midpoint".into());
+        }
+        let angle = |a: &Joint, b: &Joint, c: &Joint| {
+            let (u, w) = (v(a).sub(v(b)), v(c).sub(v(b)));
+            (u.dot(w) / (u.len() * w.len()).max(1e-6)).clamp(-1.0, 1.0).acos().to_degrees()
+        };
+        let c = &sk.constraints;
+        let hinges = [
+            ("left elbow",  angle(&self.left_shoulder,  &self.left_elbow,  &self.left_wrist),  &c.elbow),
+            ("right elbow", angle(&self.right_shoulder, &self.right_elbow, &self.right_wrist), &c.elbow),
+            ("left knee",   angle(&self.crotch, &self.left_knee,  &self.left_ankle),  &c.knee),
+            ("right knee",  angle(&self.crotch, &self.right_knee, &self.right_ankle), &c.knee),
+        ];
+        for (what, deg, range) in hinges {
+            // Half a degree of slack for f32 round-off at a fully straight limb.
+            if deg < range.min - 0.5 || deg > range.max + 0.5 {
+                return Err(format!("{what} at {deg:.0}°, outside {:.0}–{:.0}°", range.min, range.max));
+            }
+        }
+        Ok(())
+    }
+
+    /// Shift the whole figure rigidly; angles and bone lengths are untouched.
+    pub fn translate(&mut self, dx: f32, dy: f32, dz: f32) {
+        for (_, j) in self.joints_ordered_mut() { j.translate(dx, dy, dz); }
+    }
+
+    /// Set the head's nod / yaw / roll (degrees, `head_orient` conventions) and
+    /// swing the head joint to match nod and yaw, as `Pose::random` places it.
+    pub fn set_head_orientation(&mut self, nod: f32, yaw: f32, tilt: f32, sk: &crate::skeleton::Skeleton) {
+        (self.head_nod, self.head_yaw, self.head_tilt) = (nod, yaw, tilt);
+        let (sy, sn) = (yaw.to_radians().sin(), nod.to_radians().sin());
+        let up = (1.0 - sy*sy - sn*sn).max(0.0).sqrt();
+        let n = self.neck.xyz();
+        self.head.set_xyz(Self::fix_dist(n, (n.0 + sy, n.1 - up, n.2 + sn), sk.seg("neck")));
+    }
+
+    /// Re-derive nod and yaw from the neck→head direction after the head
+    /// joint was dragged or swung by the neck (same decoding as `json_loader`). Roll can't
+    /// be seen in two points and is kept.
+    fn sync_head_angles(&mut self) {
+        let d = Vec3::from_tuple(self.head.xyz()).sub(Vec3::from_tuple(self.neck.xyz()));
+        let len = d.len();
+        if len < 1e-3 { return; }
+        self.head_nod = (d.z / len).asin().to_degrees();
+        self.head_yaw = (d.x / len).asin().to_degrees();
+    }
+
+    /// Turn the whole figure about the vertical axis through the crotch,
+    /// rotating every joint's (x, z) by `degrees`. Heights, bone lengths and
+    /// the floor contact are untouched.
+    pub fn rotate_y(&mut self, degrees: f32) {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        let (cx, cz) = (self.crotch.x, self.crotch.z);
+        for (_, j) in self.joints_ordered_mut() {
+            let (dx, dz) = (j.x - cx, j.z - cz);
+            j.x = cx + dx * cos - dz * sin;
+            j.z = cz + dx * sin + dz * cos;
+        }
+        // A positive angle turns the figure to its own left; the head goes along.
+        self.head_yaw = (self.head_yaw - degrees + 180.0).rem_euclid(360.0) - 180.0;
+    }
+
+    /// One-shot symmetry: overwrite `limb` ("left_arm", "right_arm", "left_leg",
+    /// "right_leg") with the mirror image of its partner. The reflection plane is
+    /// the body midplane (normal = shoulder bar), so a twisted torso mirrors
+    /// correctly; bone lengths are re-fitted to skeleton.json afterwards.
+    pub fn mirror_limb_from(&mut self, limb: &str, sk: &crate::skeleton::Skeleton) {
+        let (to_left, is_arm) = match limb {
+            "left_arm" => (true, true),  "right_arm" => (false, true),
+            "left_leg" => (true, false), "right_leg" => (false, false),
+            _ => return,
+        };
+        let bar = Vec3::from_tuple(self.right_shoulder.xyz()).sub(Vec3::from_tuple(self.left_shoulder.xyz()));
+        let n = if bar.len() > 1e-3 { let l = bar.len(); Vec3::new(bar.x/l, bar.y/l, bar.z/l) }
+                else { Vec3::new(1.0, 0.0, 0.0) };
+        // Reflect the partner's bone vector `a→b` and hang it off `root`.
+        let mirrored = |root: (f32,f32,f32), a: (f32,f32,f32), b: (f32,f32,f32), len: f32| {
+            let v = Vec3::from_tuple(b).sub(Vec3::from_tuple(a));
+            let d = 2.0 * v.dot(n);
+            Self::fix_dist(root, (root.0 + v.x - d*n.x, root.1 + v.y - d*n.y, root.2 + v.z - d*n.z), len)
+        };
+        if is_arm {
+            let (src, dst_root) = if to_left {
+                ([self.right_shoulder.xyz(), self.right_elbow.xyz(), self.right_wrist.xyz()], self.left_shoulder.xyz())
+            } else {
+                ([self.left_shoulder.xyz(),  self.left_elbow.xyz(),  self.left_wrist.xyz()],  self.right_shoulder.xyz())
+            };
+            let el = mirrored(dst_root, src[0], src[1], sk.seg("arm"));
+            let wr = mirrored(el,       src[1], src[2], sk.seg("forearm"));
+            if to_left {
+                self.left_elbow.set_xyz(el);  self.left_wrist.set_xyz(wr);  self.left_fingers  = self.right_fingers.clone();
+            } else {
+                self.right_elbow.set_xyz(el); self.right_wrist.set_xyz(wr); self.right_fingers = self.left_fingers.clone();
+            }
+        } else {
+            let hip = self.crotch.xyz();
+            let src = if to_left { [self.right_knee.xyz(), self.right_ankle.xyz()] }
+                      else       { [self.left_knee.xyz(),  self.left_ankle.xyz()]  };
+            let kn = mirrored(hip, hip,    src[0], sk.seg("thigh"));
+            let an = mirrored(kn,  src[0], src[1], sk.seg("shin"));
+            if to_left { self.left_knee.set_xyz(kn);  self.left_ankle.set_xyz(an);  }
+            else       { self.right_knee.set_xyz(kn); self.right_ankle.set_xyz(an); }
+        }
+        self.clamp_to_floor();
+    }
+
+    /// Straighten `limb` ("left_arm", "right_arm", "left_leg", "right_leg")
+    /// while keeping its root and reach direction: the end joint slides out
+    /// along root → end to almost the full limb length, and the elbow/knee is
+    /// laid back on that line with a ~2° bend kept in the old bend plane, so a
+    /// later drag still folds the same way. Uses the limb's current bone
+    /// lengths, which FABRIK keeps equal to skeleton.json.
+    pub fn straighten_limb(&mut self, limb: &str) {
+        let (root, mid, end) = match limb {
+            "left_arm"  => (self.left_shoulder.xyz(),  self.left_elbow.xyz(),  self.left_wrist.xyz()),
+            "right_arm" => (self.right_shoulder.xyz(), self.right_elbow.xyz(), self.right_wrist.xyz()),
+            "left_leg"  => (self.crotch.xyz(), self.left_knee.xyz(),  self.left_ankle.xyz()),
+            "right_leg" => (self.crotch.xyz(), self.right_knee.xyz(), self.right_ankle.xyz()),
+            _ => return,
+        };
+        let (r, m, e) = (Vec3::from_tuple(root), Vec3::from_tuple(mid), Vec3::from_tuple(end));
+        let (l1, l2) = (r.distance(m), m.distance(e));
+        let reach = e.distance(r);
+        if reach < 1e-3 { return; }
+        let dir = { let v = e.sub(r); Vec3::new(v.x/reach, v.y/reach, v.z/reach) };
+        // 178° at the middle joint: law of cosines for the new root → end distance.
+        let bend = 178.0_f32.to_radians();
+        let span = (l1*l1 + l2*l2 - 2.0*l1*l2*bend.cos()).sqrt();
+        let along = (l1*l1 - l2*l2 + span*span) / (2.0 * span);
+        let off = (l1*l1 - along*along).max(0.0).sqrt();
+        // Old bend direction: middle joint's offset perpendicular to the reach line.
+        let rm = m.sub(r);
+        let perp = { let t = rm.dot(dir); Vec3::new(rm.x - dir.x*t, rm.y - dir.y*t, rm.z - dir.z*t) };
+        let pl = perp.len();
+        let perp = if pl > 1e-3 { Vec3::new(perp.x/pl, perp.y/pl, perp.z/pl) } else { Vec3::new(0.0, 0.0, 0.0) };
+        let new_mid = (r.x + dir.x*along + perp.x*off, r.y + dir.y*along + perp.y*off, r.z + dir.z*along + perp.z*off);
+        let new_end = (r.x + dir.x*span, r.y + dir.y*span, r.z + dir.z*span);
+        match limb {
+            "left_arm"  => { self.left_elbow.set_xyz(new_mid);  self.left_wrist.set_xyz(new_end); }
+            "right_arm" => { self.right_elbow.set_xyz(new_mid); self.right_wrist.set_xyz(new_end); }
+            "left_leg"  => { self.left_knee.set_xyz(new_mid);   self.left_ankle.set_xyz(new_end); }
+            _           => { self.right_knee.set_xyz(new_mid);  self.right_ankle.set_xyz(new_end); }
+        }
+        self.clamp_to_floor();
+    }
+
+    /// Lightweight self-collision, run when a drag is released. FABRIK only
+    /// keeps bone lengths, so a wrist can be parked inside the chest: elbows
+    /// and wrists are pushed out of a capsule around the spine (radius ¼ of the
+    /// shoulder width, well inside the skin so "hand on chest" still rests on
+    /// it), and a knee may cross the body midline by at most 0.6 shoulder
+    /// widths. Arms are re-solved with FABRIK; legs re-fitted from the hip.
+    pub fn resolve_self_collision(&mut self, sk: &crate::skeleton::Skeleton) {
+        let bar = Vec3::from_tuple(self.right_shoulder.xyz()).sub(Vec3::from_tuple(self.left_shoulder.xyz()));
+        let sw  = bar.len().max(1.0);
+        let lat = Vec3::new(bar.x/sw, bar.y/sw, bar.z/sw); // character's left → right
+        let r   = sw * 0.25;
+        let spine = [(self.neck.xyz(), self.waist.xyz()), (self.waist.xyz(), self.crotch.xyz())];
+        // Nearest point on the spine polyline; a point inside the capsule moves
+        // radially to its surface, or sideways (`out`) if it sits on the axis.
+        let push_out = |p: (f32,f32,f32), out: f32| {
+            let pv = Vec3::from_tuple(p);
+            let c = spine.iter().map(|&(a, b)| {
+                let (a, ab) = (Vec3::from_tuple(a), Vec3::from_tuple(b).sub(Vec3::from_tuple(a)));
+                let t = (pv.sub(a).dot(ab) / ab.dot(ab).max(1e-6)).clamp(0.0, 1.0);
+                Vec3::new(a.x + ab.x*t, a.y + ab.y*t, a.z + ab.z*t)
+            }).min_by(|a, b| pv.distance(*a).total_cmp(&pv.distance(*b))).unwrap_or(pv);
+            let d = pv.distance(c);
+            if d >= r { return p; }
+            let dir = if d > 1e-3 { let v = pv.sub(c); Vec3::new(v.x/d, v.y/d, v.z/d) }
+                      else { Vec3::new(lat.x*out, lat.y*out, lat.z*out) };
+            (c.x + dir.x*r, c.y + dir.y*r, c.z + dir.z*r)
+        };
+        let el = push_out(self.left_elbow.xyz(), -1.0);
+        self.fabrik_left_arm(el, sk, 1);
+        let wr = push_out(self.left_wrist.xyz(), -1.0);
+        self.fabrik_left_arm(wr, sk, 2);
+        let el = push_out(self.right_elbow.xyz(), 1.0);
+        self.fabrik_right_arm(el, sk, 1);
+        let wr = push_out(self.right_wrist.xyz(), 1.0);
+        self.fabrik_right_arm(wr, sk, 2);
+        // Knees: signed lateral offset from the crotch, + toward the knee's own side.
+        let hip = self.crotch.xyz();
+        let limit = sw * 0.6;
+        for out in [-1.0_f32, 1.0] {
+            let (kn, an) = if out < 0.0 { (self.left_knee.xyz(),  self.left_ankle.xyz()) }
+                           else         { (self.right_knee.xyz(), self.right_ankle.xyz()) };
+            let side = Vec3::from_tuple(kn).sub(Vec3::from_tuple(hip)).dot(lat) * out;
+            if side >= -limit { continue; }
+            let k = (-limit - side) * out; // slide back along `lat` to the limit
+            let shift = (lat.x*k, lat.y*k, lat.z*k);
+            let kn = Self::fix_dist(hip, (kn.0 + shift.0, kn.1 + shift.1, kn.2 + shift.2), sk.seg("thigh"));
+            let an = Self::fix_dist(kn,  (an.0 + shift.0, an.1 + shift.1, an.2 + shift.2), sk.seg("shin"));
+            if out < 0.0 { self.left_knee.set_xyz(kn);  self.left_ankle.set_xyz(an); }
+            else         { self.right_knee.set_xyz(kn); self.right_ankle.set_xyz(an); }
+        }
+        self.clamp_to_floor();
+    }
+
     /// Clamp every joint so nothing sinks below the ankle plane.
     /// Y increases downward in Pose space, so "below floor" means y > floor_y.
     /// The ankles define the floor and are never clamped themselves.
     fn clamp_to_floor(&mut self) {
         let floor_y = self.left_ankle.y.max(self.right_ankle.y);
-        for j in [
-            &mut self.head, &mut self.neck,
-            &mut self.left_shoulder,  &mut self.right_shoulder,
-            &mut self.left_elbow,     &mut self.right_elbow,
-            &mut self.left_wrist,     &mut self.right_wrist,
-            &mut self.waist,          &mut self.crotch,
-            &mut self.left_knee,      &mut self.right_knee,
-        ] {
-            if j.y > floor_y { j.y = floor_y; }
+        for (name, j) in self.joints_ordered_mut() {
+            if !name.ends_with("_ankle") && j.y > floor_y { j.y = floor_y; }
         }
     }
 
@@ -222,21 +846,21 @@ impl Pose {
 
     fn fabrik_left_arm(&mut self, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton, idx: usize) {
         let mut chain = [self.left_shoulder.xyz(), self.left_elbow.xyz(), self.left_wrist.xyz()];
-        Self::fabrik_solve(&mut chain, &[sk.seg("arm"), sk.seg("forearm")], target, idx);
+        Self::fabrik_solve(&mut chain, &[sk.seg("arm"), sk.seg("forearm")], target, idx, &sk.solver);
         self.left_elbow.set_xyz(chain[1]);
         self.left_wrist.set_xyz(chain[2]);
     }
 
     fn fabrik_right_arm(&mut self, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton, idx: usize) {
         let mut chain = [self.right_shoulder.xyz(), self.right_elbow.xyz(), self.right_wrist.xyz()];
-        Self::fabrik_solve(&mut chain, &[sk.seg("arm"), sk.seg("forearm")], target, idx);
+        Self::fabrik_solve(&mut chain, &[sk.seg("arm"), sk.seg("forearm")], target, idx, &sk.solver);
         self.right_elbow.set_xyz(chain[1]);
         self.right_wrist.set_xyz(chain[2]);
     }
 
     fn fabrik_torso(&mut self, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton, idx: usize) {
         let mut chain = [self.neck.xyz(), self.waist.xyz(), self.crotch.xyz()];
-        Self::fabrik_solve(&mut chain, &[sk.seg("torso_upper"), sk.seg("torso_lower")], target, idx);
+        Self::fabrik_solve(&mut chain, &[sk.seg("torso_upper"), sk.seg("torso_lower")], target, idx, &sk.solver);
         // chain[0] (neck) is fixed root — don't write back
         self.waist.set_xyz(chain[1]);
         self.crotch.set_xyz(chain[2]);
@@ -244,7 +868,7 @@ impl Pose {
 
     fn fabrik_left_leg(&mut self, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton, idx: usize) {
         let mut chain = [self.crotch.xyz(), self.left_knee.xyz(), self.left_ankle.xyz()];
-        Self::fabrik_solve(&mut chain, &[sk.seg("thigh"), sk.seg("shin")], target, idx);
+        Self::fabrik_solve(&mut chain, &[sk.seg("thigh"), sk.seg("shin")], target, idx, &sk.solver);
         self.crotch.set_xyz(chain[0]);
         self.left_knee.set_xyz(chain[1]);
         self.left_ankle.set_xyz(chain[2]);
@@ -252,14 +876,16 @@ impl Pose {
 
     fn fabrik_right_leg(&mut self, target: (f32, f32, f32), sk: &crate::skeleton::Skeleton, idx: usize) {
         let mut chain = [self.crotch.xyz(), self.right_knee.xyz(), self.right_ankle.xyz()];
-        Self::fabrik_solve(&mut chain, &[sk.seg("thigh"), sk.seg("shin")], target, idx);
+        Self::fabrik_solve(&mut chain, &[sk.seg("thigh"), sk.seg("shin")], target, idx, &sk.solver);
         self.crotch.set_xyz(chain[0]);
         self.right_knee.set_xyz(chain[1]);
         self.right_ankle.set_xyz(chain[2]);
     }
 
     /// Pure FABRIK — bone lengths only, no angle constraints.
-    fn fabrik_solve(chain: &mut [(f32,f32,f32)], lengths: &[f32], target: (f32,f32,f32), target_idx: usize) {
+    /// Iteration count and convergence tolerance come from skeleton.json `solver`.
+    fn fabrik_solve(chain: &mut [(f32,f32,f32)], lengths: &[f32], target: (f32,f32,f32), target_idx: usize,
+                    cfg: &crate::skeleton::SolverConfig) {
         if target_idx == 0 {
             chain[0] = target;
             for i in 0..chain.len()-1 {
@@ -271,7 +897,10 @@ impl Pose {
         let root     = chain[0];
         let target_v = Vec3::from_tuple(target);
 
-        for _ in 0..6 {
+        for _ in 0..cfg.iterations {
+            // Effector on the target (possibly before the first pass): stop
+            // iterating, but still fall through to re-seat the tail.
+            if Vec3::from_tuple(chain[target_idx]).distance(target_v) < cfg.tolerance { break; }
             // Forward: pull toward target
             chain[target_idx] = target;
             for i in (1..=target_idx).rev() {
@@ -282,7 +911,6 @@ impl Pose {
             for i in 0..target_idx {
                 chain[i+1] = Self::fix_dist(chain[i], chain[i+1], lengths[i]);
             }
-            if Vec3::from_tuple(chain[target_idx]).distance(target_v) < 0.001 { break; }
         }
 
         // Extend tail past solved joints
@@ -370,10 +998,20 @@ impl Pose {
         self.right_ankle.set_xyz(ra);
 
         // ── Re-enforce all bone lengths (skeleton never stretches) ───────────
+        self.refit_from_neck(sk);
+    }
+
+    /// Walk outward from the neck restoring every skeleton.json bone length,
+    /// keeping each bone's current direction. Re-centres the shoulder bar on
+    /// the neck.
+    fn refit_from_neck(&mut self, sk: &crate::skeleton::Skeleton) {
         let neck = self.neck.xyz();
 
-        // Head
+        // Head. A neck drag (or a strength rescale) swings it, so the stored
+        // nod/yaw are re-read from the joint to keep head_orient and the gaze
+        // overlay on the drawn head.
         self.head.set_xyz(Self::fix_dist(neck, self.head.xyz(), sk.seg("neck")));
+        self.sync_head_angles();
 
         // Shoulders: the shoulder bar is always centred on the neck.
         // Take the current shoulder direction (from the soft-moved positions)
@@ -446,4 +1084,97 @@ impl Pose {
         (from.0+dx*s, from.1+dy*s, from.2+dz*s)
     }
 
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dist(a: &Joint, b: &Joint) -> f32 {
+        ((a.x - b.x).powi(2) + (a.y - b.y).powi(2) + (a.z - b.z).powi(2)).sqrt()
+    }
+
+    #[test]
+    fn from_points_keeps_odd_geometry() {
+        // A forearm three times too long stays that way; untouched joints keep
+        // their rest positions, and head_nod is read off the moved head.
+        let rest = Pose::from_points(&[]);
+        let p = Pose::from_points(&[
+            ("left_wrist", (-32.0, 100.0, 0.0)), ("head", (0.0, -92.0, 12.0)), ("bogus", (1.0, 1.0, 1.0)),
+        ]);
+        assert_eq!(p.left_wrist.xyz(), (-32.0, 100.0, 0.0));
+        assert!(dist(&p.left_elbow, &p.left_wrist) > 3.0 * dist(&rest.left_elbow, &rest.left_wrist));
+        assert_eq!(p.right_wrist.xyz(), rest.right_wrist.xyz());
+        assert!(p.head_nod > 0.5, "chin-down nod expected, got {}", p.head_nod);
+    }
+
+    #[test]
+    fn joint_order_matches_joint_names() {
+        let mut p = Pose::from_points(&[]);
+        let names: Vec<_> = p.joints_ordered().map(|(n, _)| n).collect();
+        assert_eq!(names, JOINT_NAMES);
+        let names_mut: Vec<_> = p.joints_ordered_mut().map(|(n, _)| n).collect();
+        assert_eq!(names_mut, JOINT_NAMES);
+        // Each name resolves to a distinct field.
+        for (i, (_, j)) in p.joints_ordered_mut().enumerate() { j.set_xyz((i as f32, 0.0, 0.0)); }
+        for (i, name) in JOINT_NAMES.iter().enumerate() {
+            assert_eq!(p.joint(name).map(|j| j.x), Some(i as f32), "{name}");
+        }
+    }
+
+    #[test]
+    fn head_stays_over_tilted_shoulders() {
+        let sk = crate::skeleton::get();
+        let h = sk.head_size;
+        let centred = |p: &Pose| {
+            let mid = ((p.left_shoulder.x + p.right_shoulder.x) / 2.0,
+                       (p.left_shoulder.y + p.right_shoulder.y) / 2.0,
+                       (p.left_shoulder.z + p.right_shoulder.z) / 2.0);
+            assert!(dist(&p.neck, &Joint::new_3d(mid.0, mid.1, mid.2)) < 0.01, "neck off the shoulder midpoint");
+            assert!((p.head.x - mid.0).abs() < 0.01 && (p.head.z - mid.2).abs() < 0.01,
+                    "head drifted sideways: head {:?}, midpoint {mid:?}", p.head.xyz());
+        };
+        // Interactive drag: raise the left shoulder half a head.
+        let mut p = Pose::from_points(&[]);
+        let ls = p.left_shoulder.xyz();
+        p.move_joint("left_shoulder", (ls.0, ls.1 - 0.5 * h, ls.2), sk);
+        assert!(p.left_shoulder.y < p.right_shoulder.y - 0.25 * h);
+        centred(&p);
+        // Loader: a preset with the right shoulder dropped.
+        let points = [
+            ("head", [0.0, 7.2, 0.0]), ("neck", [0.0, 6.7, 0.0]), ("pelvis", [0.0, 4.0, 0.0]),
+            ("left_shoulder", [-0.8, 6.9, 0.0]), ("right_shoulder", [0.8, 6.4, 0.0]),
+            ("left_elbow", [-1.0, 5.4, 0.0]), ("right_elbow", [1.0, 5.0, 0.0]),
+            ("left_knee", [-0.5, 1.6, 0.0]), ("right_knee", [0.5, 1.6, 0.0]),
+        ].into_iter().map(|(k, v)| (k.to_string(), v)).collect();
+        let p = crate::pose_from_points(&points).expect("pose from points");
+        assert!(p.right_shoulder.y > p.left_shoulder.y);
+        centred(&p);
+    }
+
+    #[test]
+    fn head_drag_below_and_aside_keeps_neck_length() {
+        let sk = crate::skeleton::get();
+        let h = sk.head_size;
+        let mut p = Pose::from_points(&[]);
+        let n = p.neck.xyz();
+        p.move_joint("head", (n.0 + 2.0 * h, n.1 + h, n.2 + 0.5 * h), sk);
+        assert!((dist(&p.head, &p.neck) - sk.seg("neck")).abs() < 1e-3,
+                "neck bone {} != {}", dist(&p.head, &p.neck), sk.seg("neck"));
+        // Still pointing at the target: below the neck and off to the right.
+        assert!(p.head.y > p.neck.y && p.head.x > p.neck.x + 0.5 * sk.seg("neck"));
+    }
+
+    #[test]
+    fn random_poses_validate_and_lean_forward_along_plus_z() {
+        let sk = crate::skeleton::get();
+        for seed in 0..500 {
+            let p = Pose::random(400.0, 539.0, seed, sk);
+            assert_eq!(p.validate(sk), Ok(()), "seed {seed}");
+            // torso_lean holds the drawn forward lean in degrees.
+            let fwd = p.neck.z - p.crotch.z;
+            if p.torso_lean > 5.0 { assert!(fwd > 0.0, "seed {seed}: lean {} but neck z {fwd}", p.torso_lean); }
+            if p.torso_lean < -5.0 { assert!(fwd < 0.0, "seed {seed}: lean {} but neck z {fwd}", p.torso_lean); }
+        }
+    }
+}
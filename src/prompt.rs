@@ -4,6 +4,25 @@ use crate::app::{AppState, PresetItem};
 use crate::json_loader::{OptionsLibrary, UiConfig};
 use std::collections::HashMap;
 
+/// How the finished prompt is laid out. `Mixed` is the per-panel default
+/// (comma lists, one line per scene control); `Tags` flattens everything,
+/// pose description included, into one deduplicated comma tag list;
+/// `Sentences` turns each panel into a sentence of flowing prose.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PromptStyle { #[default] Mixed, Tags, Sentences }
+
+impl PromptStyle {
+    pub const ALL: [PromptStyle; 3] = [PromptStyle::Mixed, PromptStyle::Tags, PromptStyle::Sentences];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PromptStyle::Mixed     => "Mixed",
+            PromptStyle::Tags      => "Tags",
+            PromptStyle::Sentences => "Sentences",
+        }
+    }
+}
+
 pub struct PromptGenerator<'a> {
     state:           &'a AppState,
     libraries:       &'a HashMap<String, OptionsLibrary>,
@@ -13,6 +32,11 @@ pub struct PromptGenerator<'a> {
     ui_config:       &'a UiConfig,
     video_mode:      bool,
     pose_is_manual:  bool,
+    describe_opts:   crate::semantics::DescribeOptions,
+    preset_delta:    bool,
+    style:           PromptStyle,
+    prefix:          &'a str,
+    suffix:          &'a str,
 }
 
 impl<'a> PromptGenerator<'a> {
@@ -26,7 +50,36 @@ impl<'a> PromptGenerator<'a> {
         pose_is_manual: bool,
     ) -> Self {
         Self { state, libraries, settings_meta, presets, preset_metadata, ui_config,
-               video_mode: state.video_mode, pose_is_manual }
+               video_mode: state.video_mode, pose_is_manual,
+               describe_opts: Default::default(), preset_delta: false, style: PromptStyle::Mixed,
+               prefix: "", suffix: "" }
+    }
+
+    /// Extra detail for the live semantic pose description (verbose angles etc.).
+    pub fn describe_options(mut self, opts: crate::semantics::DescribeOptions) -> Self {
+        self.describe_opts = opts;
+        self
+    }
+
+    /// Describe an edited preset pose as its own prompt plus a `diff_summary`
+    /// tail, instead of the full live description.
+    pub fn preset_delta(mut self, on: bool) -> Self {
+        self.preset_delta = on;
+        self
+    }
+
+    /// Layout of the finished prompt (see `PromptStyle`).
+    pub fn style(mut self, style: PromptStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Fixed text put before and after the finished prompt ("masterpiece, best
+    /// quality," and the like). Blank strings add nothing.
+    pub fn boilerplate(mut self, prefix: &'a str, suffix: &'a str) -> Self {
+        self.prefix = prefix;
+        self.suffix = suffix;
+        self
     }
 
     fn skip(v: &str) -> bool { v.is_empty() || v == "None" }
@@ -35,8 +88,32 @@ impl<'a> PromptGenerator<'a> {
         match s { "video" => self.video_mode, "image" => !self.video_mode, _ => true }
     }
 
-    fn emit(out: &mut String, parts: &[String]) {
-        if !parts.is_empty() { out.push_str(&parts.join(", ")); out.push_str("\n\n"); }
+    /// A labelled chunk of the prompt. `lines` sections (scene-style control
+    /// panels) put one value per line; everything else is a comma list.
+    fn render(parts: &[String], lines: bool) -> String {
+        if lines { parts.iter().map(|p| format!("{p}\n")).collect() }
+        else     { format!("{}\n\n", parts.join(", ")) }
+    }
+
+    /// Break a section's parts into discrete tags: the live pose description
+    /// and multi-clause preset prompts are already ", "-separated phrases.
+    fn tags(parts: &[String]) -> impl Iterator<Item = &str> {
+        parts.iter().flat_map(|p| p.split([',', '\n']))
+            .map(|t| t.trim().trim_end_matches('.'))
+            .filter(|t| !t.is_empty())
+    }
+
+    /// One sentence per section: "Standing, arms crossed and head turned left."
+    fn sentence(parts: &[String]) -> String {
+        let mut clauses: Vec<&str> = Self::tags(parts).collect();
+        let Some(last) = clauses.pop() else { return String::new() };
+        let body = if clauses.is_empty() { last.to_string() } else { format!("{} and {last}", clauses.join(", ")) };
+        let mut chars = body.chars();
+        chars.next().map_or(String::new(), |c| format!("{}{}.", c.to_uppercase(), chars.as_str()))
+    }
+
+    fn emit(out: &mut Vec<(String, Vec<String>, bool)>, label: &str, parts: Vec<String>) {
+        if !parts.is_empty() { out.push((label.to_string(), parts, false)); }
     }
 
     fn val_str(v: &serde_json::Value) -> Option<String> {
@@ -48,7 +125,9 @@ impl<'a> PromptGenerator<'a> {
         // For the pose library specifically: if the user has manually moved a
         // joint, replace the preset JSON prompt with a live semantic description.
         if key == "poses" && self.pose_is_manual {
-            let desc = crate::semantics::describe(&self.state.pose);
+            if let Some(p) = self.preset_with_changes() { return vec![p]; }
+            let desc = crate::semantics::describe(&self.state.pose, &self.describe_opts,
+                                                  crate::semantics::config());
             return if desc.is_empty() { vec![] } else { vec![desc] };
         }
 
@@ -59,9 +138,114 @@ impl<'a> PromptGenerator<'a> {
             .collect()
     }
 
+    /// "<preset prompt>, with right arm moved significantly, head turned" for a
+    /// pose edited from a preset (`AppState::pose_origin`). Whole-figure moves
+    /// don't matter to a prompt and are dropped. `None` — fall back to the live
+    /// description — when the option is off, in tag mode, or once the edits
+    /// run to more than three changes and the preset's wording no longer fits.
+    fn preset_with_changes(&self) -> Option<String> {
+        if !self.preset_delta || self.describe_opts.danbooru { return None; }
+        let id = self.state.pose_origin.as_ref()?;
+        let item = self.presets.get("poses")?.iter().find(|i| &i.id == id)?;
+        let (prompt, base) = (item.prompt.as_ref()?, item.pose_data.as_ref()?);
+        let changes: Vec<String> = self.state.pose.diff_summary(base).into_iter()
+            .filter(|c| c != "whole figure moved").collect();
+        match changes.len() {
+            0     => Some(prompt.clone()),
+            1..=3 => Some(format!("{prompt}, with {}", changes.join(", "))),
+            _     => None,
+        }
+    }
+
+    /// Bind the picked props (`props.json`, in pick order) to the hands that
+    /// are posed to grip: "holding a sword in the right hand". Props left over
+    /// once the gripping hands run out are just carried — "with a shield".
+    fn held_props(&self, props: Vec<String>) -> Vec<String> {
+        let mut hands = crate::semantics::gripping_hands(&self.state.pose).into_iter();
+        props.into_iter().map(|prop| match hands.next() {
+            Some("both") => format!("holding {prop} in both hands"),
+            Some(side)   => format!("holding {prop} in the {side} hand"),
+            None         => format!("with {prop}"),
+        }).collect()
+    }
+
+    /// Framing hint from how the figure's front-view bounding box sits in the
+    /// target aspect ratio (global `aspect_ratio`). Only offered while the
+    /// framing is left at full body — an explicit crop already says it all.
+    fn composition_hint(&self) -> Option<String> {
+        let global = self.state.settings.get("global")?;
+        let aspect = global.values.get("aspect_ratio")?.as_str()?;
+        let (w, h) = aspect.split_once(':')?;
+        let frame = w.trim().parse::<f32>().ok()? / h.trim().parse::<f32>().ok()?;
+        if global.values.get("framing").and_then(|v| v.as_str()).is_some_and(|f| f != "Full Body") {
+            return None;
+        }
+        let (mut x0, mut x1, mut y0, mut y1) = (f32::MAX, f32::MIN, f32::MAX, f32::MIN);
+        for (_, j) in self.state.pose.joints_ordered() {
+            x0 = x0.min(j.x); x1 = x1.max(j.x);
+            y0 = y0.min(j.y); y1 = y1.max(j.y);
+        }
+        // Pad by the head-to-neck gap so the skull and a little air count.
+        let pad = (self.state.pose.neck.y - self.state.pose.head.y).abs();
+        let figure = (x1 - x0 + 2.0 * pad) / (y1 - y0 + 2.0 * pad).max(1.0);
+        // > 1 → frame wider than the figure needs; < 1 → figure overflows sideways.
+        let fit = frame / figure.max(0.05);
+        Some(match fit {
+            f if f >= 3.5 => "wide shot with space around the subject",
+            f if f <= 0.6 => "portrait crop, upper body",
+            _             => "full body shot",
+        }.to_string())
+    }
+
+    /// The full prompt text: every section rendered and concatenated in panel order.
     pub fn generate(&self) -> String {
-        let mut out = String::new();
+        let body = self.generate_body();
+        let (mut pre, mut suf) = (self.prefix.trim(), self.suffix.trim());
+        // Tags stay one comma-separated list, so the user's own trailing commas go.
+        if self.style == PromptStyle::Tags {
+            let junk = |c: char| c == ',' || c.is_whitespace();
+            (pre, suf) = (pre.trim_matches(junk), suf.trim_matches(junk));
+        }
+        if pre.is_empty() && suf.is_empty() { return body; }
+        // Mixed keeps its blank-line section layout; the flat styles stay one line.
+        let sep = match self.style { PromptStyle::Mixed => "\n\n", PromptStyle::Tags => ", ", PromptStyle::Sentences => " " };
+        let parts: Vec<&str> = [pre, body.trim_end(), suf].into_iter().filter(|s| !s.is_empty()).collect();
+        format!("{}\n", parts.join(sep))
+    }
+
+    fn generate_body(&self) -> String {
+        let sections = self.build();
+        match self.style {
+            PromptStyle::Mixed => sections.iter().map(|(_, parts, lines)| Self::render(parts, *lines)).collect(),
+            PromptStyle::Tags => {
+                let mut seen = std::collections::HashSet::new();
+                let tags: Vec<&str> = sections.iter().flat_map(|(_, parts, _)| Self::tags(parts))
+                    .filter(|t| seen.insert(t.to_lowercase())).collect();
+                if tags.is_empty() { String::new() } else { format!("{}\n", tags.join(", ")) }
+            }
+            PromptStyle::Sentences => {
+                let text: Vec<String> = sections.iter().map(|(_, parts, _)| Self::sentence(parts)).collect();
+                if text.is_empty() { String::new() } else { format!("{}\n", text.join(" ")) }
+            }
+        }
+    }
+
+    /// The prompt split by source panel, as `(label, text)` pairs, for callers
+    /// that want to copy or display one section at a time.
+    pub fn generate_sections(&self) -> Vec<(String, String)> {
+        self.build().into_iter()
+            .map(|(label, parts, lines)| (label, match self.style {
+                PromptStyle::Mixed     => Self::render(&parts, lines).trim_end().to_string(),
+                PromptStyle::Tags      => Self::tags(&parts).collect::<Vec<_>>().join(", "),
+                PromptStyle::Sentences => Self::sentence(&parts),
+            }))
+            .collect()
+    }
+
+    fn build(&self) -> Vec<(String, Vec<String>, bool)> {
+        let mut out = Vec::new();
         for panel in &self.ui_config.panels {
+            if self.state.muted_sections.contains(&panel.title) { continue }
             let key = panel.data_source.trim_end_matches(".json");
             match panel.panel_type.as_str() {
                 "options_grid" => {
@@ -82,7 +266,7 @@ impl<'a> PromptGenerator<'a> {
                     let mut remaining: Vec<_> = groups.into_iter().collect();
                     remaining.sort_by_key(|(k, _)| k.clone());
                     for (_, v) in remaining { all.extend(v); }
-                    Self::emit(&mut out, &all);
+                    Self::emit(&mut out, &panel.title, all);
                 }
                 "controls" => {
                     let Some(lib)  = self.settings_meta.get(key)   else { continue };
@@ -93,32 +277,46 @@ impl<'a> PromptGenerator<'a> {
                             let disp = Self::val_str(data.values.get(&s.id)?)?;
                             (!Self::skip(&disp)).then(|| format!("{}: {}", s.label, disp))
                         }).collect();
-                        Self::emit(&mut out, &pairs);
+                        Self::emit(&mut out, &panel.title, pairs);
                     } else {
                         // Iterate by lib.settings (Vec) order, not data.values (HashMap),
                         // so the output is stable and won't reshuffle on each update_prompt().
-                        for s in &lib.settings {
-                            if let Some(v) = data.values.get(&s.id) {
-                                if let Some(d) = Self::val_str(v) {
-                                    if !Self::skip(&d) { out.push_str(&d); out.push('\n'); }
-                                }
-                            }
-                        }
+                        let lines: Vec<_> = lib.settings.iter()
+                            .filter_map(|s| Self::val_str(data.values.get(&s.id)?))
+                            .filter(|d| !Self::skip(d))
+                            .collect();
+                        if !lines.is_empty() { out.push((panel.title.clone(), lines, true)); }
                     }
                 }
                 "preset_selector" => {
                     let Some(meta) = self.preset_metadata.get(key) else { continue };
                     if !self.include(&meta.include_prompt)          { continue }
-                    Self::emit(&mut out, &self.selected_prompts(key));
+                    let mut parts = self.selected_prompts(key);
+                    if key == "poses" && !parts.is_empty() { parts.extend(self.composition_hint()); }
+                    if key == "props" { parts = self.held_props(parts); }
+                    Self::emit(&mut out, &panel.title, parts);
                 }
                 "composite" => {
                     for comp in &panel.components {
                         let ckey = comp.data_source.trim_end_matches(".json");
                         if self.libraries.get(ckey).map_or(true, |l| self.include(&l.include_prompt)) {
-                            Self::emit(&mut out, &self.selected_prompts(ckey));
+                            Self::emit(&mut out, &comp.label, self.selected_prompts(ckey));
                         }
                     }
                 }
+                "sequence" => {
+                    if !self.video_mode { continue }
+                    let mut parts: Vec<String> = Vec::new();
+                    parts.extend(crate::semantics::describe_arc(&self.state.keyframes));
+                    // One verb phrase per keyframe transition; collapse repeats so a
+                    // multi-key walk reads "walking forward", not the same words thrice.
+                    let mut motion: Vec<String> = self.state.keyframes.windows(2)
+                        .filter_map(|w| crate::semantics::describe_motion(&w[0], &w[1]))
+                        .collect();
+                    motion.dedup();
+                    if !motion.is_empty() { parts.push(motion.join(", then ")); }
+                    Self::emit(&mut out, &panel.title, parts);
+                }
                 _ => {}
             }
         }
@@ -4,7 +4,8 @@
 // Coordinate space (screen coords at scale=40):
 //   X : negative = character's LEFT,  positive = character's RIGHT
 //   Y : SMALLER  = higher on screen  (Y increases downward)
-//   Z : negative = toward viewer,    positive = into scene
+//   Z : positive = character's forward, toward the viewer (the Front camera
+//       sits at +Z, see Camera3D::eye);  negative = behind, away from the viewer
 //
 // All spatial reasoning is done in a body-relative frame via BodyMetrics so
 // that ground height, torso proportions, and shoulder width are consistent
@@ -16,25 +17,578 @@
 //   This keeps left/right arm and leg logic symmetric around identical thresholds.
 
 use crate::pose::Pose;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Optional extra detail for `describe`. The default is the terse prompt text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DescribeOptions {
+    /// Append approximate angles (e.g. "leg raised ~110° to the side") where the
+    /// coarse height buckets lose information that dance/martial-arts users want.
+    pub verbose: bool,
+    /// Say "weight evenly distributed" for planted, symmetric stances instead of
+    /// staying silent below the contrapposto threshold.
+    pub even_weight: bool,
+    /// Emit underscore-joined Danbooru tags (`standing, arms_up`) instead of
+    /// prose, for anime-focused models. See `to_danbooru_tags`.
+    pub danbooru: bool,
+}
+
+/// Classifier sensitivity, loaded from semantics_thresholds.json. Each band is
+/// [slight, moderate, strong]; the defaults are the original hardcoded values.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct SemanticsConfig {
+    /// Torso forward/back lean, degrees from vertical.
+    pub lean_forward_deg:   [f32; 3],
+    /// Torso sideways tilt, degrees from vertical.
+    pub lean_side_deg:      [f32; 3],
+    /// Shoulder height difference that reads as "raised", fraction of torso height.
+    pub shoulder_tilt_frac: f32,
+    /// Shoulder-bar rotation: slightly turned / turned / in profile, degrees.
+    pub twist_deg:          [f32; 3],
+    /// Hip offset over the feet for contrapposto, fraction of shoulder width.
+    pub contrapposto_frac:  [f32; 3],
+    /// Ankle height that counts as a lifted foot, fraction of body height.
+    pub foot_raise_frac:    f32,
+    /// Waist off the neck→crotch line that reads as a curved back, fraction of torso length.
+    pub spine_curve_frac:   f32,
+}
+
+impl Default for SemanticsConfig {
+    fn default() -> Self {
+        Self {
+            lean_forward_deg:   [12.0, 26.0, 50.0],
+            lean_side_deg:      [10.0, 18.0, 30.0],
+            shoulder_tilt_frac: 0.11,
+            twist_deg:          [16.0, 34.0, 62.0],
+            contrapposto_frac:  [0.22, 0.38, 0.55],
+            foot_raise_frac:    0.08,
+            spine_curve_frac:   0.25,
+        }
+    }
+}
+
+static CFG: OnceLock<SemanticsConfig> = OnceLock::new();
+
+/// Thresholds from semantics_thresholds.json (disk override first, then embedded).
+/// A malformed file falls back to the defaults rather than breaking the prompt.
+pub fn config() -> &'static SemanticsConfig {
+    CFG.get_or_init(|| crate::json_loader::load("semantics_thresholds.json")
+        .unwrap_or_else(|e| { eprintln!("Warning: {e}"); SemanticsConfig::default() }))
+}
+
+/// `describe` split into its parts, for tools that want individual fields
+/// (serialises to JSON). `to_string()` gives exactly the `describe` text.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct PoseDescription {
+    /// Stance, or a reference pose ("standing in T-pose") that replaces the breakdown.
+    pub stance: String,
+    /// Lean, twist, spine curve and propping, in that order; for lying poses,
+    /// the body arrangement from `lying_detail` instead.
+    pub torso:  Vec<String>,
+    pub weight: Option<String>,
+    pub head:   Option<String>,
+    pub arms:   Option<String>,
+    pub legs:   Option<String>,
+    /// "mid-stride walking" / "running"; may stand in for a bare stride in `legs`.
+    pub gait:   Option<String>,
+}
 
-pub fn describe(pose: &Pose) -> String {
+impl std::fmt::Display for PoseDescription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<&str> = std::iter::once(self.stance.as_str())
+            .chain(self.torso.iter().map(String::as_str))
+            .chain([&self.weight, &self.head, &self.arms, &self.legs, &self.gait]
+                .into_iter().filter_map(|s| s.as_deref()))
+            .collect();
+        f.write_str(&parts.join(", "))
+    }
+}
+
+pub fn describe(pose: &Pose, opts: &DescribeOptions, cfg: &SemanticsConfig) -> String {
+    let prose = describe_structured(pose, opts, cfg).to_string();
+    if opts.danbooru { to_danbooru_tags(&prose).join(", ") } else { prose }
+}
+
+/// The classifier results behind `describe`, one field per body region.
+/// `opts.danbooru` is ignored — tags are a rendering of the joined text.
+pub fn describe_structured(pose: &Pose, opts: &DescribeOptions, cfg: &SemanticsConfig) -> PoseDescription {
     let m = BodyMetrics::new(pose);
-    let mut parts: Vec<String> = Vec::new();
-    let stance_str = stance(pose, &m);
-    parts.push(stance_str.clone());
+    let stance_str = stance(pose, &m, opts, cfg);
+    // Canonical reference poses stand in for the whole stance/lean/limb breakdown.
+    if let Some(r) = reference_pose(pose, &m, &stance_str, cfg) {
+        return PoseDescription { stance: r.into(), head: head_orient(pose), ..Default::default() };
+    }
+    let mut d = PoseDescription { stance: stance_str.clone(), ..Default::default() };
     let is_lying = stance_str.starts_with("lying");
+    // A fold already says where the torso and (when low) the hands are;
+    // a sprinter's start says torso, hands and legs outright.
+    let sprint = stance_str.contains("sprinter's start");
+    let folded = sprint || stance_str.contains("bent forward at the hips");
     // Torso lean/twist are meaningless when lying — and actively harmful: the
     // body is horizontal so |neck.y − crotch.y| collapses to near-zero, causing
     // the lean calculation to divide by ~1 px and produce huge spurious angles.
-    if !is_lying {
-        if let Some(s) = torso_lean(pose)   { parts.push(s); }
-        if let Some(s) = torso_twist(pose)  { parts.push(s); }
-    }
-    if let Some(s) = weight_shift(pose, &m, &stance_str) { parts.push(s); }
-    if let Some(s) = head_orient(pose)      { parts.push(s); }
-    if let Some(s) = arms(pose, &m)         { parts.push(s); }
-    if let Some(s) = legs(pose, &m, &stance_str) { parts.push(s); }
-    parts.join(", ")
+    if !is_lying && !folded {
+        d.torso.extend(torso_lean(pose, cfg));
+        d.torso.extend(torso_twist(pose, cfg));
+        d.torso.extend(spine_curve(pose, cfg));
+        d.torso.extend(propped(pose, &m, &stance_str, cfg));
+    }
+    if is_lying { d.torso = lying_detail(pose, &m, &stance_str); }
+    d.weight = weight_shift(pose, &m, &stance_str, opts, cfg);
+    d.head   = head_orient(pose);
+    if !(sprint || (folded && (stance_str.ends_with("toes") || stance_str.ends_with("floor")))) {
+        d.arms = arms(pose, &m);
+    }
+    dramatic_reach(pose, &m, &mut d);
+    over_shoulder(&mut d);
+    if opts.verbose { face_detail(pose, &m, &mut d); }
+    let legs_str = legs(pose, &m, &stance_str, opts);
+    d.gait = gait(pose, &m, &stance_str, legs_str.as_deref()).map(str::to_string);
+    // Gait replaces a bare "legs in stride" / "legs straight" — the arm phrases
+    // stay, since they still say which arm is swinging where.
+    let replaced = d.gait.is_some() && legs_str.as_deref().is_some_and(|l| l.contains("stride") || l == "legs straight");
+    if !replaced && !sprint { d.legs = legs_str; }
+    d
+}
+
+/// Post-pass: both hands above the head and behind the shoulders (−Z, with
+/// +Z forward as in torso_lean) together with a backward lean or arched back reads as one
+/// gesture. The lean/arch clause and the arm phrase give way to a single
+/// "arching back with arms reaching overhead"; any other torso clause (a
+/// raised shoulder, a twist) is kept. No change unless both halves match.
+fn dramatic_reach(p: &Pose, m: &BodyMetrics, d: &mut PoseDescription) {
+    let up_back = |w: &crate::pose::Joint, s: &crate::pose::Joint| w.y < p.head.y && s.z - w.z > m.torso_h * 0.10;
+    if !(up_back(&p.left_wrist, &p.left_shoulder) && up_back(&p.right_wrist, &p.right_shoulder)) { return; }
+    let is_back = |c: &str| c == "back arched" || (c.starts_with("leaning") && c.ends_with(" back"));
+    if !d.torso.iter().any(|t| t.split(", ").any(is_back)) { return; }
+    let mut torso = vec!["arching back with arms reaching overhead".to_string()];
+    torso.extend(d.torso.iter()
+        .map(|t| t.split(", ").filter(|c| !is_back(c)).collect::<Vec<_>>().join(", "))
+        .filter(|t| !t.is_empty()));
+    d.torso = torso;
+    d.arms  = None;
+}
+
+/// Post-pass: a clearly turned body ("body turned right", "in profile,
+/// facing right") with the head turned the other way is the classic glance
+/// back over the shoulder. The twist clause becomes "turned away, looking
+/// back over the left shoulder" and the head loses its yaw clause; nod and
+/// roll stay. A slight body turn or a head turned the same way is left alone.
+fn over_shoulder(d: &mut PoseDescription) {
+    let Some(i) = d.torso.iter().position(|t| t.starts_with("body turned") || t.starts_with("in profile")) else { return };
+    let body = if d.torso[i].ends_with("right") { "right" } else { "left" };
+    let Some(head) = d.head.as_deref() else { return };
+    let yaw = |c: &str| c.starts_with("head turned") || c.starts_with("glancing");
+    let Some(turn) = head.split(", ").find(|c| yaw(c)) else { return };
+    let look = if turn.ends_with("left") { "left" } else { "right" };
+    if look == body { return; }
+    d.torso[i] = format!("turned away, looking back over the {look} shoulder");
+    let rest: Vec<&str> = head.split(", ").filter(|c| !yaw(c)).collect();
+    d.head = (!rest.is_empty()).then(|| rest.join(", "));
+}
+
+/// Verbose hand-to-face detail. describe_arm only sees a hand at the head
+/// when the arm is low enough to dodge its "raised" bands, so this looks at
+/// the wrists directly. With a single head point the region is a guess from
+/// the wrist's offset: out to the side → ear, in front at or above the head
+/// centre → eyes (facepalm when the head is bowed), in front and lower →
+/// mouth. Both hands on the same region become one phrase, two different
+/// regions replace the arm phrase outright, and a single hand replaces only
+/// its own side's clause.
+fn face_detail(p: &Pose, m: &BodyMetrics, d: &mut PoseDescription) {
+    let t = m.torso_h;
+    let bowed = d.head.as_deref().is_some_and(|h| h.contains("down"));
+    let region = |wr: &crate::pose::Joint| -> Option<&'static str> {
+        if mag(sub(wr.xyz(), p.head.xyz())) > t * 0.30 { return None; }
+        let in_front = wr.z > p.head.z + 5.0;
+        let dy = wr.y - p.head.y;
+        if dy < -t * 0.15 { return None; } // on top of the head, not the face
+        if !in_front && (wr.x - p.head.x).abs() > t * 0.12 {
+            Some(if wr.x < p.head.x { "left ear" } else { "right ear" })
+        } else if !in_front { None }
+        else if dy < t * 0.02 { Some("eyes") }
+        else { Some("mouth") }
+    };
+    let (l, r) = (region(&p.left_wrist), region(&p.right_wrist));
+    let both = match (l, r) {
+        (Some("eyes"), Some("eyes")) if bowed => Some("face buried in hands".to_string()),
+        (Some(a), Some(b)) if a == b => Some(format!("hands over {a}")),
+        (Some(a), Some(b)) if a.ends_with("ear") && b.ends_with("ear") => Some("hands over ears".to_string()),
+        _ => None,
+    };
+    if let Some(b) = both { d.arms = Some(b); return; }
+    let phrase = |side: &str, reg: &str| if reg == "eyes" && bowed { format!("{side} hand pressed to forehead (facepalm)") }
+                                         else { format!("{side} hand over {reg}") };
+    if let (Some(a), Some(b)) = (l, r) { d.arms = Some(format!("{}, {}", phrase("left", a), phrase("right", b))); return; }
+    for (side, reg) in [("left", l), ("right", r)] {
+        let Some(reg) = reg else { continue };
+        let phrase = phrase(side, reg);
+        let arms = d.arms.take().unwrap_or_default();
+        let mut clauses: Vec<String> = arms.split(", ").filter(|c| !c.is_empty()).map(str::to_string).collect();
+        match clauses.iter_mut().find(|c| c.starts_with(&format!("{side} "))) {
+            Some(c) => *c = phrase,
+            // Collapsed "arms …" phrase or nothing for this side: lead with the hand.
+            None => clauses.insert(0, phrase),
+        }
+        d.arms = Some(clauses.join(", "));
+    }
+}
+
+/// Phrase → Danbooru tag table for `to_danbooru_tags`. A phrase matches an
+/// entry when it contains every needle; the first match wins, so the more
+/// specific entries come first. Phrases with no entry are dropped — a tag
+/// model gets nothing useful from "other foot forward".
+const DANBOORU: &[(&[&str], &[&str])] = &[
+    // Stance
+    (&["t-pose"],                 &["standing", "t-pose"]),
+    (&["a-pose"],                 &["standing", "a-pose"]),
+    (&["tip-toe"],                &["standing", "tiptoes"]),
+    (&["figure-4"],               &["standing_on_one_leg", "leg_up"]),
+    (&["balancing on"],           &["standing_on_one_leg"]),
+    (&["knee raised"],            &["standing_on_one_leg", "knee_up"]),
+    (&["splits"],                 &["splits"]),
+    (&["cross-legged"],           &["sitting", "indian_style"]),
+    (&["lotus position"],         &["sitting", "lotus_position"]),
+    (&["seated"],                 &["sitting"]),
+    (&["perched"],                &["sitting"]),
+    (&["kneeling on"],            &["kneeling", "on_one_knee"]),
+    (&["kneeling"],               &["kneeling"]),
+    (&["sprinter's start"],       &["squatting", "hands_on_ground"]),
+    (&["squat"],                  &["squatting"]),
+    (&["lying", "face down"],     &["lying", "on_stomach"]),
+    (&["lying", "face up"],       &["lying", "on_back"]),
+    (&["lying on"],               &["lying", "on_side"]),
+    (&["fetal position"],         &["fetal_position"]),
+    (&["recovery position"],      &["on_side"]),
+    (&["standing"],               &["standing"]),
+    // Head, ahead of the torso: "head tilted left" must not hit "tilted" + "left".
+    (&["head bowed"],             &["head_down"]),
+    (&["head tilted back"],       &["looking_up"]),
+    (&["looking up"],             &["looking_up"]),
+    (&["looking slightly up"],    &["looking_up"]),
+    (&["looking slightly down"],  &["looking_down"]),
+    (&["head turned"],            &["looking_to_the_side"]),
+    (&["glancing"],               &["looking_to_the_side"]),
+    (&["head", "tilted"],         &["head_tilt"]),
+    // Torso
+    (&["possibly against"],       &["leaning_to_the_side", "against_wall"]),
+    (&["leaning", "forward"],     &["leaning_forward"]),
+    (&["leaning", "back"],        &["leaning_back"]),
+    (&["tilted", "left"],         &["leaning_to_the_side"]),
+    (&["tilted", "right"],        &["leaning_to_the_side"]),
+    (&["in profile"],             &["profile"]),
+    (&["looking back over"],      &["looking_back", "looking_at_viewer"]),
+    (&["bent forward at"],        &["bent_over"]),
+    (&["arching back"],           &["arched_back", "arms_up"]),
+    (&["hunched"],                &["hunched_over"]),
+    (&["back arched"],            &["arched_back"]),
+    (&["propped"],                &["arm_support"]),
+    (&["weight on"],              &["contrapposto"]),
+    // Arms
+    (&["hands on hips"],          &["hands_on_hips"]),
+    (&["hand on hip"],            &["hand_on_hip"]),
+    (&["hands on knees"],         &["hands_on_own_knees"]),
+    (&["hands clasped behind"],   &["arms_behind_back"]),
+    (&["hands clasped"],          &["own_hands_clasped"]),
+    (&["hands pressed together"], &["own_hands_together"]),
+    (&["both hands on chest"],    &["hands_on_own_chest"]),
+    (&["hand on chest"],          &["hand_on_own_chest"]),
+    (&["hands at neck"],          &["hands_on_own_neck"]),
+    (&["hand at neck"],           &["hand_on_own_neck"]),
+    (&["behind head"],            &["hand_behind_head"]),
+    (&["hand at chin"],           &["hand_on_own_chin"]),
+    (&["facepalm"],               &["facepalm"]),
+    (&["buried in hands"],        &["covering_face"]),
+    (&["over eyes"],              &["covering_eyes"]),
+    (&["over mouth"],             &["covering_mouth"]),
+    (&["over", " ear"],           &["covering_ears"]),
+    (&["covering face"],          &["covering_face"]),
+    (&["guard position"],         &["fighting_stance"]),
+    (&["arms crossed"],           &["crossed_arms"]),
+    (&["arms folded"],            &["crossed_arms"]),
+    (&["arms behind back"],       &["arms_behind_back"]),
+    (&["arm behind back"],        &["arm_behind_back"]),
+    (&["overhead"],               &["arms_up"]),
+    (&["straight up"],            &["arms_up"]),
+    (&["arms raised"],            &["arms_up"]),
+    (&["arm raised"],             &["arm_up"]),
+    (&["arm pointing up"],        &["arm_up"]),
+    (&["arms outstretched"],      &["outstretched_arms", "spread_arms"]),
+    (&["arm outstretched"],       &["outstretched_arm"]),
+    (&["toward viewer"],          &["reaching_towards_viewer"]),
+    (&["arms reaching"],          &["reaching"]),
+    (&["arm reaching"],           &["reaching"]),
+    (&["arms extended forward"],  &["outstretched_arms"]),
+    (&["straight out in front"],  &["outstretched_arms"]),
+    (&["forward with both hands"], &["reaching"]),
+    (&["arm extended forward"],   &["outstretched_arm"]),
+    (&["arms at sides"],          &["arms_at_sides"]),
+    (&["arms resting at sides"],  &["arms_at_sides"]),
+    (&["waving"],                 &["waving"]),
+    // Legs
+    (&["legs crossed"],           &["crossed_legs"]),
+    (&["ankles crossed"],         &["crossed_ankles"]),
+    (&["feet together"],          &["feet_together"]),
+    (&["legs together"],          &["legs_together"]),
+    (&["very wide apart"],        &["legs_apart"]),
+    (&["wide apart"],             &["legs_apart"]),
+    (&["legs spread"],            &["spread_legs"]),
+    (&["leg raised"],             &["leg_up"]),
+    (&["kicking"],                &["kicking"]),
+    (&["running"],                &["running"]),
+    (&["walking"],                &["walking"]),
+];
+
+/// Map a `describe` string to Danbooru tags, in phrase order without
+/// duplicates. Adds `looking_at_viewer` when nothing turns the head or body
+/// away and the figure isn't face-down.
+pub fn to_danbooru_tags(desc: &str) -> Vec<String> {
+    let desc = desc.to_lowercase();
+    let mut tags: Vec<String> = Vec::new();
+    for phrase in desc.split(", ") {
+        let Some((_, hit)) = DANBOORU.iter().find(|(needles, _)| needles.iter().all(|n| phrase.contains(n)))
+            else { continue };
+        for t in hit.iter() {
+            if !tags.iter().any(|x| x == t) { tags.push(t.to_string()); }
+        }
+    }
+    let averted = ["turned", "glancing", "in profile", "face down", "bowed", "looking up", "tilted back"];
+    if !desc.is_empty() && !averted.iter().any(|a| desc.contains(a)) {
+        tags.push("looking_at_viewer".into());
+    }
+    tags
+}
+
+/// "standing, bent forward at the hips[, touching toes]": feet planted, legs
+/// mostly straight with the hips held high, and the neck dropped to hip level
+/// or lower in front of the body (+Z, as in torso_lean). Measured against leg
+/// length, since the usual body-height fractions collapse in a fold.
+fn forward_fold(p: &Pose, m: &BodyMetrics) -> Option<String> {
+    let leg_len = leg_len(p);
+    let straight = angle_at(p.crotch.xyz(), p.left_knee.xyz(),  p.left_ankle.xyz())  > 140.0
+                && angle_at(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz()) > 140.0;
+    let planted  = m.above_floor(p.left_ankle.y).max(m.above_floor(p.right_ankle.y)) < leg_len * 0.10;
+    let hips_up  = m.above_floor(p.crotch.y) > leg_len * 0.75;
+    let torso    = mag(sub(p.neck.xyz(), p.crotch.xyz())).max(1.0);
+    let neck_low = p.neck.y > p.crotch.y - torso * 0.25;
+    if !(straight && planted && hips_up && neck_low && p.neck.z > p.crotch.z) { return None; }
+
+    let near_ankle = |w: &crate::pose::Joint| [&p.left_ankle, &p.right_ankle].iter()
+        .any(|a| mag(sub(w.xyz(), a.xyz())) < leg_len * 0.30);
+    let low = |w: &crate::pose::Joint| m.above_floor(w.y) < leg_len * 0.35;
+    let hands = if near_ankle(&p.left_wrist) && near_ankle(&p.right_wrist) { ", touching toes" }
+                else if low(&p.left_wrist) || low(&p.right_wrist)          { ", reaching toward the floor" }
+                else { "" };
+    Some(format!("standing, bent forward at the hips{hands}"))
+}
+
+/// Hip-to-ankle length along the bones, averaged over both legs. Unlike
+/// body height it doesn't shrink when the figure folds or crouches.
+fn leg_len(p: &Pose) -> f32 {
+    let leg = |hip: V3, kn: V3, an: V3| mag(sub(kn, hip)) + mag(sub(an, kn));
+    (leg(p.crotch.xyz(), p.left_knee.xyz(), p.left_ankle.xyz())
+   + leg(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz())) / 2.0
+}
+
+/// Body arrangement for a lying stance, which legs() and the torso checks skip:
+/// the leg shape from knee bend and hip flexion (the angle at the crotch between
+/// the neck and the knee), then, on the back or front, which way the head points
+/// along the floor (+Z toward the viewer, see header). Side-lying already says
+/// which way the body runs, so it only gets the legs. Raised legs are checked
+/// before the tuck: legs up in the air also flex the hips, but their feet stay
+/// up off the floor rather than drawn in to the seat.
+fn lying_detail(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Vec<String> {
+    let knee = |k: &crate::pose::Joint, a: &crate::pose::Joint| angle_at(p.crotch.xyz(), k.xyz(), a.xyz());
+    let hip  = |k: &crate::pose::Joint| angle_at(p.neck.xyz(), p.crotch.xyz(), k.xyz());
+    let (l_k, r_k) = (knee(&p.left_knee, &p.left_ankle), knee(&p.right_knee, &p.right_ankle));
+    let (l_h, r_h) = (hip(&p.left_knee), hip(&p.right_knee));
+    let on_side = stance_str.starts_with("lying on");
+    // Lengths are measured against the thigh: torso_h collapses when lying.
+    let thigh = |k: &crate::pose::Joint| mag(sub(k.xyz(), p.crotch.xyz())).max(1.0);
+    // A tucked leg has its foot drawn in close to the crotch, not just bent.
+    let near_seat = |a: &crate::pose::Joint| mag(sub(a.xyz(), p.crotch.xyz())) < thigh(&p.left_knee) * 1.1;
+    let (l_tuck, r_tuck) = (l_k < 100.0 && l_h < 100.0 && near_seat(&p.left_ankle),
+                            r_k < 100.0 && r_h < 100.0 && near_seat(&p.right_ankle));
+    let straight = |k: f32, h: f32| k > 150.0 && h > 150.0;
+    // Thighs pointing up off the floor, with knees and feet kept in over the
+    // hips (splayed legs seen from above also run up the frame) and the feet
+    // not hanging well below the hips.
+    let raised = |k: &crate::pose::Joint, a: &crate::pose::Joint| {
+        let inline = |j: &crate::pose::Joint| (j.x - p.crotch.x).abs() < thigh(k) * 0.5;
+        p.crotch.y - k.y > thigh(k) * 0.6 && inline(k) && inline(a) && a.y < p.crotch.y + thigh(k) * 0.25
+    };
+
+    let mut out = Vec::new();
+    if !on_side && raised(&p.left_knee, &p.left_ankle) && raised(&p.right_knee, &p.right_ankle) {
+        out.push("legs raised".to_string());
+    } else if l_tuck && r_tuck {
+        out.push(if on_side { "curled up in fetal position" } else { "knees pulled to the chest" }.to_string());
+    } else if on_side && ((l_tuck && r_k > 140.0) || (r_tuck && l_k > 140.0)) {
+        out.push("in recovery position".to_string());
+    } else if straight(l_k, l_h) && straight(r_k, r_h) {
+        out.push("legs extended".to_string());
+    } else if l_k < 120.0 && r_k < 120.0 {
+        out.push("knees bent".to_string());
+    }
+
+    if !on_side {
+        let (dx, dz) = (p.head.x - p.crotch.x, p.head.z - p.crotch.z);
+        if dz.abs() > dx.abs() && dz.abs() > m.torso_h * 0.5 {
+            out.push(if dz > 0.0 { "head toward the viewer" } else { "feet toward the viewer" }.to_string());
+        } else if dx.abs() > m.torso_h * 0.5 {
+            out.push("stretched across the frame".to_string());
+        }
+    }
+    out
+}
+
+/// Sprinter's crouch start: hips low, a bent front leg with the other foot
+/// well behind it (+Z forward, as in torso_lean), and both hands planted on
+/// the floor ahead of the hips. The hands are what separate it from a squat
+/// or a low lunge, so this is the one stance check that reads the wrists;
+/// the shoulders must also be held up off the floor, which rules out lying.
+fn sprint_start(p: &Pose, m: &BodyMetrics) -> bool {
+    let leg_len = leg_len(p);
+    let low     = m.above_floor(p.crotch.y) < leg_len * 0.60;
+    let feet    = m.above_floor(p.left_ankle.y).max(m.above_floor(p.right_ankle.y)) < leg_len * 0.20;
+    let split   = (p.left_ankle.z - p.right_ankle.z).abs() > leg_len * 0.35;
+    let bent    = angle_at(p.crotch.xyz(), p.left_knee.xyz(),  p.left_ankle.xyz())  < 120.0
+               || angle_at(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz()) < 120.0;
+    let planted = |w: &crate::pose::Joint| m.above_floor(w.y) < leg_len * 0.15 && w.z > p.crotch.z;
+    // Shoulders held up on straight-ish arms, not resting on the floor (lying).
+    let propped = m.above_floor(p.neck.y) > leg_len * 0.40;
+    low && feet && split && bent && propped && planted(&p.left_wrist) && planted(&p.right_wrist)
+}
+
+/// "mid-stride walking" / "running": a stride (or a driven knee) with the arms
+/// swinging in opposition — the arm opposite the lead leg forward, the other
+/// back. Running when the stride is long with a knee well up, or when the
+/// stance is already a single raised knee. Uses describe_arm/describe_leg's
+/// +Z-forward convention.
+fn gait(p: &Pose, m: &BodyMetrics, stance_str: &str, legs_str: Option<&str>) -> Option<&'static str> {
+    let knee_up = stance_str.contains("knee raised");
+    // legs() only calls a stride once each leg is well off vertical; a normal
+    // walking step is shorter, so also accept feet clearly split front-to-back
+    // — unless legs() saw a sideways spread, and only with the front foot
+    // actually ahead of the hips.
+    let stride  = (p.left_ankle.z - p.right_ankle.z).abs() / m.shoulder_w;
+    let spread  = legs_str.is_some_and(|l| l.contains("spread") || l.contains("wide"));
+    let ahead   = p.left_ankle.z.max(p.right_ankle.z) > p.crotch.z;
+    let striding = legs_str.is_some_and(|l| l.contains("stride")) || (stride > 0.8 && !spread && ahead);
+    let walking_stance = striding && stance_str.starts_with("standing");
+    if !knee_up && !walking_stance { return None; }
+    let lead = |a: &crate::pose::Joint, k: &crate::pose::Joint| (a.z + k.z) / 2.0;
+    let left_leads = lead(&p.left_ankle, &p.left_knee) > lead(&p.right_ankle, &p.right_knee);
+    let swing = |w: &crate::pose::Joint, s: &crate::pose::Joint| (w.z - s.z) / m.torso_h;
+    let (l_arm, r_arm) = (swing(&p.left_wrist, &p.left_shoulder), swing(&p.right_wrist, &p.right_shoulder));
+    let (fwd_arm, back_arm) = if left_leads { (r_arm, l_arm) } else { (l_arm, r_arm) };
+    if fwd_arm < 0.15 || back_arm > -0.10 { return None; }
+    let high_kn = m.height_frac(p.left_knee.y).max(m.height_frac(p.right_knee.y)) > 0.32;
+    Some(if knee_up || (stride > 1.5 && high_kn) { "running" } else { "mid-stride walking" })
+}
+
+/// The stance label alone ("standing", "kneeling on one knee", …) with
+/// default options and the loaded thresholds, before any reference-pose
+/// name replaces it — for callers and regression checks that only need the
+/// class.
+pub fn classify_stance(pose: &Pose) -> String {
+    stance(pose, &BodyMetrics::new(pose), &DescribeOptions::default(), config())
+}
+
+/// Raw classification inputs for the canvas debug overlay: the `BodyMetrics`
+/// reference units plus the stance they produced, as (label, value) rows.
+pub fn pose_stats(pose: &Pose) -> Vec<(&'static str, String)> {
+    let m = BodyMetrics::new(pose);
+    vec![
+        ("body height",     format!("{:.0}px", m.body_h)),
+        ("torso height",    format!("{:.0}px", m.torso_h)),
+        ("shoulder width",  format!("{:.0}px", m.shoulder_w)),
+        ("crotch height",   format!("{:.2}", m.height_frac(pose.crotch.y))),
+        ("stance",          classify_stance(pose)),
+    ]
+}
+
+/// Hands posed as if gripping something, for binding held props: a bent
+/// elbow with the hand above the hips (a hanging or hip-resting hand doesn't
+/// count). `["both"]` when both hands grip close together, as on a sword
+/// hilt; otherwise `"right"` before `"left"`.
+pub fn gripping_hands(pose: &Pose) -> Vec<&'static str> {
+    let m = BodyMetrics::new(pose);
+    let grips = |sh: V3, el: V3, wr: V3| angle_at(sh, el, wr) < 150.0 && wr.1 < m.hip_y;
+    let right = grips(pose.right_shoulder.xyz(), pose.right_elbow.xyz(), pose.right_wrist.xyz());
+    let left  = grips(pose.left_shoulder.xyz(),  pose.left_elbow.xyz(),  pose.left_wrist.xyz());
+    if right && left && mag(sub(pose.right_wrist.xyz(), pose.left_wrist.xyz())) < m.shoulder_w * 0.4 {
+        return vec!["both"];
+    }
+    [(right, "right"), (left, "left")].into_iter().filter(|(g, _)| *g).map(|(_, s)| s).collect()
+}
+
+/// Summarise a keyframe sequence as a start → end stance arc for video prompts.
+/// Returns None with fewer than two keyframes.
+pub fn describe_arc(keyframes: &[Pose]) -> Option<String> {
+    if keyframes.len() < 2 { return None; }
+    let (first, last) = (keyframes.first()?, keyframes.last()?);
+    let opts = DescribeOptions::default();
+    let from = stance(first, &BodyMetrics::new(first), &opts, config());
+    let to   = stance(last,  &BodyMetrics::new(last),  &opts, config());
+    Some(if from == to { format!("remains {from}") } else { format!("transitions from {from} to {to}") })
+}
+
+/// Action verbs for the transition between two consecutive keyframes.
+/// Each candidate is scored by its joint delta in torso-height units; the two
+/// largest are reported, biggest first. None when nothing moved noticeably.
+pub fn describe_motion(from: &Pose, to: &Pose) -> Option<String> {
+    let m0 = BodyMetrics::new(from);
+    let m1 = BodyMetrics::new(to);
+    let unit = m0.torso_h;
+    let mut cues: Vec<(f32, String)> = Vec::new();
+
+    // ── Whole-body travel: crotch translation in the ground plane ─────────────
+    let (dx, dz) = (to.crotch.x - from.crotch.x, to.crotch.z - from.crotch.z);
+    let travel = (dx * dx + dz * dz).sqrt() / unit;
+    if travel > 0.25 {
+        let verb = if travel > 0.80 { "running" } else { "walking" };
+        let dir  = if dz.abs() >= dx.abs() { if dz > 0.0 { "forward" } else { "backward" } }
+                   else if dx > 0.0 { "to the right" } else { "to the left" };
+        cues.push((travel, format!("{verb} {dir}")));
+    }
+
+    // ── Vertical body motion ──────────────────────────────────────────────────
+    let rise = (from.crotch.y - to.crotch.y) / unit; // + = crotch moved up
+    if rise.abs() > 0.40 {
+        cues.push((rise.abs(), if rise > 0.0 { "rising up".into() } else { "lowering down".into() }));
+    }
+
+    // ── Kicks: an ankle lifts well off its previous height ────────────────────
+    for (side, a0, a1) in [("left",  &from.left_ankle,  &to.left_ankle),
+                           ("right", &from.right_ankle, &to.right_ankle)] {
+        let lift = m1.height_frac(a1.y) - m0.height_frac(a0.y);
+        if lift > 0.20 {
+            cues.push((lift * m0.body_h / unit, format!("kicking with {side} leg")));
+        }
+    }
+
+    // ── Arm swings: large wrist travel; above the shoulder reads as a wave ────
+    let mut swings: Vec<(f32, &str, bool)> = Vec::new();
+    for (side, w0, w1, sh) in [("left",  &from.left_wrist,  &to.left_wrist,  &to.left_shoulder),
+                               ("right", &from.right_wrist, &to.right_wrist, &to.right_shoulder)] {
+        let d = mag(sub(w1.xyz(), w0.xyz())) / unit;
+        if d > 0.60 { swings.push((d, side, w1.y < sh.y)); }
+    }
+    match swings.as_slice() {
+        [(d, side, true)]                   => cues.push((*d, format!("waving {side} arm"))),
+        [(d, side, false)]                  => cues.push((*d, format!("swinging {side} arm"))),
+        [(d0, _, up0), (d1, _, up1)]        => cues.push((d0.max(*d1),
+            if *up0 && *up1 { "waving both arms".into() } else { "swinging arms".into() })),
+        _ => {}
+    }
+
+    cues.sort_by(|a, b| b.0.total_cmp(&a.0));
+    cues.truncate(2);
+    if cues.is_empty() { return None; }
+    Some(cues.into_iter().map(|(_, s)| s).collect::<Vec<_>>().join(" while "))
 }
 
 // ─── Body reference frame ─────────────────────────────────────────────────────
@@ -127,7 +681,7 @@ fn angle_at(a: V3, b: V3, c: V3) -> f32 {
 fn raised_foot_dir(hip: V3, ankle: V3, sign: f32) -> &'static str {
     let ha   = sub(ankle, hip);
     let ha_m = mag(ha).max(1e-6);
-    let fwd  =  ha.2 / ha_m;            // +1 = into scene = character's forward
+    let fwd  =  ha.2 / ha_m;            // +1 = character's forward (+Z, toward the viewer)
     let lat  =  ha.0 * sign / ha_m;     // +1 = outward from body centre
     let up   = -ha.1 / ha_m;            // +1 = ankle above hip
 
@@ -145,7 +699,37 @@ fn raised_foot_dir(hip: V3, ankle: V3, sign: f32) -> &'static str {
     else                                   { " behind"      }
 }
 
-fn stance(p: &Pose, m: &BodyMetrics) -> String {
+/// Bend of a raised (not bent past 120°) leg, appended to the balancing phrase.
+/// A straight leg lifted behind to hip height or above is an arabesque. Barely
+/// lifted feet get nothing — the bend isn't visible at that height.
+fn raised_leg_shape(knee_angle: f32, height: &str, dir: &str) -> &'static str {
+    if height == "slightly raised" { return ""; }
+    let straight = knee_angle > 160.0;
+    let high     = height != "raised to knee height";
+    match (straight, dir) {
+        (true, " behind") if high => ", leg extended in an arabesque",
+        (true, _)                 => ", leg extended",
+        (false, _)                => ", knee bent",
+    }
+}
+
+/// Leg raise angle for verbose mode, measured from hanging straight down (0°)
+/// through horizontal (90°) to straight up (180°). Rounded to 5° — joint
+/// placement is never more precise than that.
+fn raise_angle(hip: V3, ankle: V3) -> f32 {
+    let ha   = sub(ankle, hip);
+    let ha_m = mag(ha).max(1e-6);
+    let deg  = (ha.1 / ha_m).clamp(-1.0, 1.0).acos().to_degrees();
+    (deg / 5.0).round() * 5.0
+}
+
+fn stance(p: &Pose, m: &BodyMetrics, opts: &DescribeOptions, cfg: &SemanticsConfig) -> String {
+    // Forward fold first: with the head hanging near the floor, body_h shrinks
+    // enough to trip the lying check below, and every height fraction is off.
+    if let Some(s) = forward_fold(p, m) { return s; }
+    // Same reason for the sprinter's crouch: the head drops to hip height.
+    if sprint_start(p, m) { return "crouched in sprinter's start position".into(); }
+
     // Lying: body nearly horizontal — head and ankles at very similar Y.
     if m.body_h < 80.0 {
         // Side-lying: head is offset laterally from the crotch by more than the
@@ -168,31 +752,58 @@ fn stance(p: &Pose, m: &BodyMetrics) -> String {
     // Shin direction — the key to distinguishing sitting/kneeling/crouching.
     let l_shin_down = p.left_ankle.y  > p.left_knee.y  + 20.0; // foot below knee
     let r_shin_down = p.right_ankle.y > p.right_knee.y + 20.0;
-    let l_shin_back = p.left_ankle.z  > p.left_knee.z  + 20.0; // foot behind knee
-    let r_shin_back = p.right_ankle.z > p.right_knee.z + 20.0;
+    let l_shin_back = p.left_ankle.z  < p.left_knee.z  - 20.0; // foot behind knee
+    let r_shin_back = p.right_ankle.z < p.right_knee.z - 20.0;
 
     let crotch_h  = m.height_frac(p.crotch.y);
     let knee_z    = (p.left_knee.z + p.right_knee.z) / 2.0;
     let spread    = m.foot_spread(p.left_ankle.x, p.right_ankle.x);
 
+    // ── One knee down (proposal stance) ──────────────────────────────────────
+    // Tested per leg against the floor: one knee almost on the ground with its
+    // shin trailing back, the other foot planted ahead of it with the knee up.
+    // Must precede the two-knees-bent kneeling branch, which would swallow it.
+    let one_knee_down = |kk: &crate::pose::Joint, ka: &crate::pose::Joint, shin_back: bool,
+                         ok: &crate::pose::Joint, oa: &crate::pose::Joint| {
+        shin_back && m.height_frac(kk.y) < 0.10
+            && m.height_frac(oa.y) < 0.05 && m.height_frac(ok.y) > 0.18
+            && oa.z > kk.z + 20.0 && oa.z > ka.z
+    };
+    if (l_bent || r_bent)
+        && (one_knee_down(&p.left_knee,  &p.left_ankle,  l_shin_back, &p.right_knee, &p.right_ankle)
+         || one_knee_down(&p.right_knee, &p.right_ankle, r_shin_back, &p.left_knee,  &p.left_ankle)) {
+        return "kneeling on one knee, other foot forward (proposal stance)".into();
+    }
+
     if l_bent && r_bent {
-        // ── Kneeling: shins going backward into scene, crotch not too high ───
+        // ── Lotus: each foot up on the opposite thigh ────────────────────────
+        // Ankles sit at knee/thigh height rather than on the floor, and cross
+        // well past each other. The shins run back along the thighs, so this
+        // must precede kneeling, which would read them as shins going back.
+        let ankles_up = (p.left_ankle.y + p.right_ankle.y) / 2.0
+            < (p.left_knee.y + p.right_knee.y) / 2.0 + m.torso_h * 0.05;
+        let deep_cross = p.left_ankle.x - p.right_ankle.x > m.shoulder_w * 0.4;
+        if ankles_up && deep_cross && crotch_h < 0.30 {
+            return "seated in lotus position".into();
+        }
+        // ── Kneeling: shins running back (−Z) from the knees, crotch not too high ─
         if (l_shin_back || r_shin_back) && crotch_h < 0.50 {
             // Torso lean forward over knees → "kneeling, torso forward"
             let torso_fwd = p.neck.z - p.crotch.z;
             let vert      = (p.crotch.y - p.neck.y).abs().max(1.0);
-            if torso_fwd < -vert * 0.30 {
+            if torso_fwd > vert * 0.30 {
                 return "kneeling, torso leaning forward".into();
             }
             return "kneeling".into();
         }
         // ── Seated variants ──────────────────────────────────────────────────
         if l_shin_down && r_shin_down {
-            // Cross-legged: left ankle has crossed to the right of the right ankle.
+            // Cross-legged: left ankle has crossed to the right of the right ankle,
+            // feet low on the floor (raised, deeply crossed feet are lotus above).
             if p.left_ankle.x > p.right_ankle.x {
                 return "seated cross-legged".into();
             }
-            let knees_fwd = p.crotch.z - knee_z > 20.0;
+            let knees_fwd = knee_z - p.crotch.z > 20.0;
             if knees_fwd || crotch_h > 0.38 {
                 // High crotch with feet down = perching on the edge of a seat.
                 if crotch_h > 0.52 {
@@ -206,31 +817,56 @@ fn stance(p: &Pose, m: &BodyMetrics) -> String {
         return format!("{depth}squat");
     }
 
+    // ── Figure-4: bent leg's ankle resting on the opposite (standing) knee ──
+    let fig4 = m.torso_h * 0.30;
+    if l_bent && !r_bent && mag(sub(p.left_ankle.xyz(), p.right_knee.xyz())) < fig4 {
+        return "standing on right leg, left ankle resting on right knee (figure-4)".into();
+    }
+    if r_bent && !l_bent && mag(sub(p.right_ankle.xyz(), p.left_knee.xyz())) < fig4 {
+        return "standing on left leg, right ankle resting on left knee (figure-4)".into();
+    }
+
     // ── One knee bent ────────────────────────────────────────────────────────
+    // A knee pulled up in front to hip height, foot off the floor, is tucked
+    // to the chest (knee-to-chest balance, high-knee sprint). In front means
+    // +Z, toward the viewer (see header).
+    let tucked = |k: &crate::pose::Joint, a: &crate::pose::Joint|
+        m.height_frac(k.y) >= crotch_h - 0.02 && k.z > p.crotch.z + m.torso_h * 0.30
+            && m.above_floor(a.y) > m.body_h * cfg.foot_raise_frac;
     if l_bent && !r_bent {
         return if l_shin_back { "kneeling on left knee".into() }
+               else if tucked(&p.left_knee, &p.left_ankle) { "left knee raised to the chest".into() }
                else { "left knee raised".into() };
     }
     if r_bent && !l_bent {
         return if r_shin_back { "kneeling on right knee".into() }
+               else if tucked(&p.right_knee, &p.right_ankle) { "right knee raised to the chest".into() }
                else { "right knee raised".into() };
     }
 
     // ── Standing — check for one foot off the ground ─────────────────────────
     // floor_y = lower (grounded) ankle; the raised ankle will be smaller Y.
-    let raise_threshold = m.body_h * 0.08; // at least 8% of body height
+    let raise_threshold = m.body_h * cfg.foot_raise_frac; // default 8% of body height
     let l_raised = m.above_floor(p.left_ankle.y);
     let r_raised = m.above_floor(p.right_ankle.y);
 
     if l_raised > raise_threshold && r_raised < raise_threshold / 2.0 {
-        let h   = m.foot_raise_desc(p.left_ankle.y);
         let dir = raised_foot_dir(p.crotch.xyz(), p.left_ankle.xyz(), -1.0);
-        return format!("balancing on right leg, left foot {h}{dir}");
+        if opts.verbose {
+            let a = raise_angle(p.crotch.xyz(), p.left_ankle.xyz());
+            return format!("balancing on right leg, left leg raised ~{a:.0}°{dir}");
+        }
+        let h   = m.foot_raise_desc(p.left_ankle.y);
+        return format!("balancing on right leg, left foot {h}{dir}{}", raised_leg_shape(l_ka, h, dir));
     }
     if r_raised > raise_threshold && l_raised < raise_threshold / 2.0 {
-        let h   = m.foot_raise_desc(p.right_ankle.y);
         let dir = raised_foot_dir(p.crotch.xyz(), p.right_ankle.xyz(), 1.0);
-        return format!("balancing on left leg, right foot {h}{dir}");
+        if opts.verbose {
+            let a = raise_angle(p.crotch.xyz(), p.right_ankle.xyz());
+            return format!("balancing on left leg, right leg raised ~{a:.0}°{dir}");
+        }
+        let h   = m.foot_raise_desc(p.right_ankle.y);
+        return format!("balancing on left leg, right foot {h}{dir}{}", raised_leg_shape(r_ka, h, dir));
     }
 
     // ── Splits: legs very wide AND crotch near the floor ────────────────────
@@ -243,7 +879,7 @@ fn stance(p: &Pose, m: &BodyMetrics) -> String {
             return "doing the side splits".into();
         }
         if sag_ratio >= 1.60 {
-            let fwd_leg = if p.left_ankle.z < p.right_ankle.z { "left" } else { "right" };
+            let fwd_leg = if p.left_ankle.z > p.right_ankle.z { "left" } else { "right" };
             return format!("doing the forward splits, {fwd_leg} leg forward");
         }
     }
@@ -264,9 +900,40 @@ fn stance(p: &Pose, m: &BodyMetrics) -> String {
     format!("standing, {spread}")
 }
 
+// ─── Reference poses ──────────────────────────────────────────────────────────
+
+/// "standing in T-pose" / "standing in A-pose": upright, legs straight and close,
+/// both arms straight and out to the sides — level with the shoulders (T) or
+/// angled ~45° down (A). These are modelling-reference terms, so they replace
+/// the per-limb description rather than adding to it.
+fn reference_pose(p: &Pose, m: &BodyMetrics, stance_str: &str, cfg: &SemanticsConfig) -> Option<&'static str> {
+    if !stance_str.starts_with("standing, ") || torso_lean(p, cfg).is_some() { return None; }
+    let l_ka = angle_at(p.crotch.xyz(), p.left_knee.xyz(),  p.left_ankle.xyz());
+    let r_ka = angle_at(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz());
+    let spread_ratio = (p.left_ankle.x - p.right_ankle.x).abs() / m.shoulder_w;
+    if l_ka < 165.0 || r_ka < 165.0 || spread_ratio >= 0.40 { return None; }
+
+    // Degrees the straight arm drops below horizontal; None unless it's
+    // straight and pointing out sideways (little forward/back component).
+    let drop = |sh: V3, el: V3, wr: V3, sign: f32| -> Option<f32> {
+        if angle_at(sh, el, wr) < 160.0 { return None; }
+        let (dx, dy, dz) = (sign * (wr.0 - sh.0), wr.1 - sh.1, wr.2 - sh.2);
+        if dx <= 0.0 || dz.abs() > dx * 0.35 { return None; }
+        Some(dy.atan2(dx).to_degrees())
+    };
+    let l = drop(p.left_shoulder.xyz(),  p.left_elbow.xyz(),  p.left_wrist.xyz(),  -1.0)?;
+    let r = drop(p.right_shoulder.xyz(), p.right_elbow.xyz(), p.right_wrist.xyz(),  1.0)?;
+    let band = |lo: f32, hi: f32| (lo..=hi).contains(&l) && (lo..=hi).contains(&r);
+    if band(-15.0, 15.0) { Some("standing in T-pose") }
+    else if band(30.0, 60.0) { Some("standing in A-pose") }
+    else { None }
+}
+
 // ─── Torso lean ───────────────────────────────────────────────────────────────
 
-fn torso_lean(p: &Pose) -> Option<String> {
+fn torso_lean(p: &Pose, cfg: &SemanticsConfig) -> Option<String> {
+    let [fwd_slight, fwd_mid, fwd_far] = cfg.lean_forward_deg;
+    let [side_slight, side_mid, side_far] = cfg.lean_side_deg;
     let lean_x = p.neck.x - p.crotch.x;
     let lean_z = p.neck.z - p.crotch.z;
     let vert   = (p.crotch.y - p.neck.y).abs().max(1.0);
@@ -274,20 +941,20 @@ fn torso_lean(p: &Pose) -> Option<String> {
     let fwd_angle  = (lean_z.abs() / vert).atan().to_degrees();
     let side_angle = (lean_x.abs() / vert).atan().to_degrees();
 
-    let fwd = if lean_z < -25.0 && fwd_angle > 12.0 {
-        if fwd_angle > 50.0 { Some("leaning far forward") }
-        else if fwd_angle > 26.0 { Some("leaning forward") }
+    let fwd = if lean_z > 25.0 && fwd_angle > fwd_slight {
+        if fwd_angle > fwd_far { Some("leaning far forward") }
+        else if fwd_angle > fwd_mid { Some("leaning forward") }
         else { Some("leaning slightly forward") }
-    } else if lean_z > 25.0 && fwd_angle > 12.0 {
-        if fwd_angle > 50.0 { Some("leaning far back") }
-        else if fwd_angle > 26.0 { Some("leaning back") }
+    } else if lean_z < -25.0 && fwd_angle > fwd_slight {
+        if fwd_angle > fwd_far { Some("leaning far back") }
+        else if fwd_angle > fwd_mid { Some("leaning back") }
         else { Some("leaning slightly back") }
     } else { None };
 
-    let side = if side_angle > 10.0 {
-        if side_angle > 30.0 {
+    let side = if side_angle > side_slight {
+        if side_angle > side_far {
             if lean_x < 0.0 { Some("tilted far left") } else { Some("tilted far right") }
-        } else if side_angle > 18.0 {
+        } else if side_angle > side_mid {
             if lean_x < 0.0 { Some("tilted left") } else { Some("tilted right") }
         } else {
             if lean_x < 0.0 { Some("tilted slightly left") } else { Some("tilted slightly right") }
@@ -299,7 +966,7 @@ fn torso_lean(p: &Pose) -> Option<String> {
     let base = match (fwd, side) {
         (Some(_f), Some(_s)) => {
             // Classify the combined direction into an 8-point compass word.
-            let fwd_dir  = if lean_z < 0.0 { "forward" } else { "back" };
+            let fwd_dir  = if lean_z > 0.0 { "forward" } else { "back" };
             let side_dir = if lean_x < 0.0 { "left"    } else { "right" };
             let intensity = if fwd_angle > 35.0 || side_angle > 25.0 { "leaning" } else { "leaning slightly" };
             Some(format!("{intensity} {fwd_dir} and to the {side_dir}"))
@@ -312,7 +979,7 @@ fn torso_lean(p: &Pose) -> Option<String> {
     // Shoulder tilt: one shoulder noticeably higher than the other.
     // Threshold is proportional to torso height so it stays consistent at any body scale.
     let sh_dy = p.left_shoulder.y - p.right_shoulder.y; // negative = left shoulder higher
-    let sh_tilt_threshold = (p.crotch.y - p.neck.y).abs() * 0.11; // ~12 px at default scale=40
+    let sh_tilt_threshold = (p.crotch.y - p.neck.y).abs() * cfg.shoulder_tilt_frac; // ~12 px at default scale=40
     let sh_tilt = if sh_dy < -sh_tilt_threshold * 2.0 { Some("left shoulder sharply raised") }
                   else if sh_dy < -sh_tilt_threshold   { Some("left shoulder raised") }
                   else if sh_dy > sh_tilt_threshold * 2.0 { Some("right shoulder sharply raised") }
@@ -330,44 +997,96 @@ fn torso_lean(p: &Pose) -> Option<String> {
 // ─── Torso twist ─────────────────────────────────────────────────────────────
 // Detects rotation of the shoulder bar in the XZ plane.
 // When square-on to the camera the shoulder vector is purely lateral (dz ≈ 0).
-// Z positive = into scene = character's forward, so:
+// ─── Spine curve ──────────────────────────────────────────────────────────────
+
+// How far the waist sits off the straight neck→crotch line, front-to-back.
+// Same forward sign as torso_lean (+Z = forward): a waist pushed behind the
+// line (−Z) is a rounded, hunched back; pushed in front (belly out, chest
+// lifted) is an arched back. Presets load with the waist on the line, so only
+// a waist that was dragged or posed off it is described.
+fn spine_curve(p: &Pose, cfg: &SemanticsConfig) -> Option<String> {
+    let (n, w, c) = (p.neck.xyz(), p.waist.xyz(), p.crotch.xyz());
+    let line = sub(c, n);
+    let len2 = dot(line, line);
+    if len2 < 1.0 { return None; }
+    let t = (dot(sub(w, n), line) / len2).clamp(0.0, 1.0);
+    let behind = (n.2 + line.2 * t) - w.2; // + = waist behind the line
+    let limit = len2.sqrt() * cfg.spine_curve_frac;
+    if behind > limit { Some("hunched, back rounded".into()) }
+    else if behind < -limit { Some("back arched".into()) }
+    else { None }
+}
+
+// Z positive = character's forward = toward the viewer, so:
 //   dz > 0  → left shoulder closer to viewer, right further → character turned to their RIGHT
 //   dz < 0  → right shoulder closer, left further          → character turned to their LEFT
-fn torso_twist(p: &Pose) -> Option<String> {
+fn torso_twist(p: &Pose, cfg: &SemanticsConfig) -> Option<String> {
+    let [slight, turned, profile] = cfg.twist_deg;
     let dz = p.left_shoulder.z - p.right_shoulder.z;
     let dx = (p.left_shoulder.x - p.right_shoulder.x).abs().max(1.0);
     // Angle between shoulder bar and the pure-lateral axis (0° = square, 90° = profile)
     let twist_deg = dz.abs().atan2(dx).to_degrees();
-    if twist_deg < 16.0 { return None; }
+    if twist_deg < slight { return None; }
     let dir = if dz > 0.0 { "right" } else { "left" };
-    Some(if twist_deg > 62.0 {
+    Some(if twist_deg > profile {
         format!("in profile, facing {dir}")
-    } else if twist_deg > 34.0 {
+    } else if twist_deg > turned {
         format!("body turned {dir}")
     } else {
         format!("body slightly turned {dir}")
     })
 }
 
+// ─── Propped / leaning on a surface ───────────────────────────────────────────
+// We can't model the wall, but its signature is recognisable: a clear sideways
+// torso lean plus either a straight arm braced down-and-out on the lean side or
+// crossed ankles (the relaxed "shoulder on the wall" portrait). Conservative on
+// purpose — a plain side tilt is already covered by torso_lean().
+fn propped(p: &Pose, m: &BodyMetrics, stance_str: &str, cfg: &SemanticsConfig) -> Option<String> {
+    if !stance_str.starts_with("standing") { return None; }
+    let lean_x = p.neck.x - p.crotch.x;
+    let side_angle = (lean_x.abs() / m.torso_h).atan().to_degrees();
+    if side_angle < cfg.lean_side_deg[1] { return None; }
+
+    let (side, sign, sh, el, wr) = if lean_x < 0.0 {
+        ("left",  -1.0, &p.left_shoulder,  &p.left_elbow,  &p.left_wrist)
+    } else {
+        ("right",  1.0, &p.right_shoulder, &p.right_elbow, &p.right_wrist)
+    };
+    let straight = angle_at(sh.xyz(), el.xyz(), wr.xyz()) > 155.0;
+    let down     = wr.y - sh.y > m.torso_h * 0.30;
+    let out      = (wr.x - sh.x) * sign > m.shoulder_w * 0.25;
+    if straight && down && out {
+        return Some(format!("weight propped on {side} arm"));
+    }
+
+    let ankles_crossed = p.left_ankle.x > p.right_ankle.x + 8.0;
+    ankles_crossed.then(|| "leaning to one side, possibly against a surface".into())
+}
+
 // ─── Weight shift ─────────────────────────────────────────────────────────────
 // Contrapposto / weight on one foot. Only meaningful when both feet are grounded.
 // Hip (crotch) offset from the ankle midpoint tells us which leg bears the load.
-fn weight_shift(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Option<String> {
+fn weight_shift(p: &Pose, m: &BodyMetrics, stance_str: &str, opts: &DescribeOptions,
+                cfg: &SemanticsConfig) -> Option<String> {
+    let [slight, clear, strong] = cfg.contrapposto_frac;
     // Contrapposto is only meaningful when upright and both feet are planted.
     // For seated, kneeling, squat etc. the hip offset is irrelevant or misleading.
     if !stance_str.starts_with("standing") { return None; }
-    let raise_threshold = m.body_h * 0.08;
+    let raise_threshold = m.body_h * cfg.foot_raise_frac;
     // Skip if either foot is raised — stance() already describes that case.
     if m.above_floor(p.left_ankle.y)  > raise_threshold { return None; }
     if m.above_floor(p.right_ankle.y) > raise_threshold { return None; }
     let ankle_mid_x = (p.left_ankle.x + p.right_ankle.x) / 2.0;
     let hip_offset  = p.crotch.x - ankle_mid_x;
-    // Threshold: 22% of shoulder width — subtle but clear contrapposto.
-    if hip_offset.abs() < m.shoulder_w * 0.22 { return None; }
+    // Threshold: contrapposto_frac[0] (default 22%) of shoulder width — subtle but clear.
+    if hip_offset.abs() < m.shoulder_w * slight {
+        return opts.even_weight.then(|| "weight evenly distributed".into());
+    }
     // Magnitude gradation: slight / clear / pronounced contrapposto.
     let side = if hip_offset > 0.0 { "right" } else { "left" };
-    let magnitude = if hip_offset.abs() > m.shoulder_w * 0.55 { "strongly " }
-                    else if hip_offset.abs() > m.shoulder_w * 0.38 { "" }
+    let magnitude = if hip_offset.abs() > m.shoulder_w * strong { "strongly " }
+                    else if hip_offset.abs() > m.shoulder_w * clear { "" }
                     else { "slightly " };
     Some(format!("{magnitude}weight on {side} foot"))
 }
@@ -376,17 +1095,13 @@ fn weight_shift(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Option<String> {
 // ─── Head orientation ─────────────────────────────────────────────────────────
 
 fn head_orient(p: &Pose) -> Option<String> {
-    let d = norm(sub(p.head.xyz(), p.neck.xyz()));
-    let nod_deg = (-d.2).asin().to_degrees(); // + = chin toward viewer (looking down)
-    let yaw_deg = d.0.asin().to_degrees();    // + = turned to character's right
-
-    // Head roll: lateral tilt of the head (ear toward shoulder).
-    // Approximated by measuring how far the head drifts laterally relative to
-    // the neck, normalised against the head-to-neck segment length.
-    // Positive = head tilted toward character's right shoulder.
-    let neck_to_head_len = mag(sub(p.head.xyz(), p.neck.xyz())).max(1.0);
-    let roll_x  = p.head.x - p.neck.x;
-    let roll_deg = (roll_x / neck_to_head_len).clamp(-1.0, 1.0).asin().to_degrees();
+    // Orientation is stored on the pose rather than re-derived from the
+    // neck→head vector, so it can be set exactly (🙂 Head Orientation) and
+    // roll — invisible in two points — sits alongside nod and yaw. Loaders,
+    // head drags and the random generator keep the fields in step with the joint.
+    let nod_deg  = p.head_nod;  // + = chin toward viewer (looking down)
+    let yaw_deg  = p.head_yaw;  // + = turned to character's right
+    let roll_deg = p.head_tilt; // + = ear toward the character's right shoulder
 
     let nod = match nod_deg as i32 {
         n if n >  35 => Some("head bowed down"),
@@ -509,23 +1224,19 @@ fn arms(p: &Pose, m: &BodyMetrics) -> Option<String> {
         if l_at_hip && r_at_hip && l_out && r_out && l_angle < 120.0 && r_angle < 120.0 {
             return Some("hands on hips".into());
         }
-        // ── One hand on hip (akimbo) — fall through to per-arm for the other side
+        // ── One hand on hip (akimbo) — the other arm described individually
+        // when describe_arm has something to say about it, omitted otherwise.
+        let akimbo = |side: &str, other: Option<String>| Some(match other {
+            Some(d) => format!("{side} hand on hip, {d}"),
+            None    => format!("{side} hand on hip"),
+        });
         if l_at_hip && l_out && l_angle < 120.0 && !(r_at_hip && r_out && r_angle < 120.0) {
-            // Record left akimbo; right arm will be described individually below.
-            // Return early only if right arm is also classifiable as "at side" or similar,
-            // otherwise rely on per-arm logic by breaking out.
-            let r_desc = describe_arm(p.right_shoulder.xyz(), p.right_elbow.xyz(),
-                                      p.right_wrist.xyz(), head, "right", m);
-            if let Some(rd) = r_desc {
-                return Some(format!("left hand on hip, {rd}"));
-            }
+            return akimbo("left", describe_arm(p.right_shoulder.xyz(), p.right_elbow.xyz(),
+                                               p.right_wrist.xyz(), head, "right", m));
         }
         if r_at_hip && r_out && r_angle < 120.0 && !(l_at_hip && l_out && l_angle < 120.0) {
-            let l_desc = describe_arm(p.left_shoulder.xyz(), p.left_elbow.xyz(),
-                                      p.left_wrist.xyz(), head, "left", m);
-            if let Some(ld) = l_desc {
-                return Some(format!("right hand on hip, {ld}"));
-            }
+            return akimbo("right", describe_arm(p.left_shoulder.xyz(), p.left_elbow.xyz(),
+                                                p.left_wrist.xyz(), head, "left", m));
         }
     }
 
@@ -560,6 +1271,18 @@ fn arms(p: &Pose, m: &BodyMetrics) -> Option<String> {
         }
     }
 
+    // ── At ease — hands clasped behind the back ──────────────────────────────
+    // Both wrists close together and well behind the crotch. Uses describe_arm's
+    // convention (+Z = character's forward), so "behind" is wrist.z < crotch.z.
+    // Must run before the fig-leaf check, which ignores depth entirely.
+    {
+        let wr_dist = mag(sub(p.left_wrist.xyz(), p.right_wrist.xyz()));
+        let behind  = |w: &crate::pose::Joint| p.crotch.z - w.z > m.torso_h * 0.15;
+        if wr_dist < m.torso_h * 0.30 && behind(&p.left_wrist) && behind(&p.right_wrist) {
+            return Some("hands clasped behind back".into());
+        }
+    }
+
     // ── Parade rest / fig-leaf — wrists crossed at pelvis ────────────────────
     // Both wrists near the hip/pelvis level and very close together.
     // Wrist overlap (one in front of the other in X) distinguishes from clasped hands.
@@ -581,6 +1304,62 @@ fn arms(p: &Pose, m: &BodyMetrics) -> Option<String> {
     let right = describe_arm(p.right_shoulder.xyz(), p.right_elbow.xyz(),
                              p.right_wrist.xyz(), head, "right", m);
 
+    // ── Both arms overhead: celebration, reach, or holding a prop ─────────────
+    // Keyed off describe_arm's overhead band. Bent elbows with wrists flung wide
+    // reads as cheering/surrender; locked elbows with wrists close is a reach;
+    // wrists nearly together is a two-handed grip on something held aloft.
+    {
+        let l_over = left.as_deref().is_some_and(|s| s.starts_with("left arm overhead"));
+        let r_over = right.as_deref().is_some_and(|s| s.starts_with("right arm overhead"));
+        if l_over && r_over {
+            let l_ang  = angle_at(p.left_shoulder.xyz(),  p.left_elbow.xyz(),  p.left_wrist.xyz());
+            let r_ang  = angle_at(p.right_shoulder.xyz(), p.right_elbow.xyz(), p.right_wrist.xyz());
+            let wr_sep = (p.left_wrist.x - p.right_wrist.x).abs() / m.shoulder_w;
+            if l_ang < 150.0 && r_ang < 150.0 && wr_sep > 1.40 {
+                return Some("arms raised overhead in celebration".into());
+            }
+            if l_ang > 160.0 && r_ang > 160.0 && wr_sep < 1.20 {
+                return Some("both arms reaching straight up".into());
+            }
+            // Wrists close but not touching (the clasped check above already took
+            // those) — gripping a sword, bar or other prop held aloft.
+            let wr_dist = mag(sub(p.left_wrist.xyz(), p.right_wrist.xyz()));
+            if wr_dist < m.torso_h * 0.45 {
+                return Some("holding something overhead with both hands".into());
+            }
+            if l_ang < 150.0 && r_ang < 150.0 {
+                return Some("arms raised, hands apart overhead".into());
+            }
+        }
+    }
+
+    // ── Both arms forward: reaching, held out, or offering ────────────────────
+    // "arms extended forward at chest level" is accurate but clinical. Wrists
+    // together at shoulder height reach for something; straight arms apart at
+    // that height are held out in front; at chest level and apart, hands are offered.
+    {
+        let fwd = |d: &Option<String>, side: &str| d.as_deref()
+            .is_some_and(|s| s.starts_with(&format!("{side} arm extended forward")));
+        if fwd(&left, "left") && fwd(&right, "right") {
+            let (l_lvl, r_lvl) = (m.level_name(p.left_wrist.y), m.level_name(p.right_wrist.y));
+            let high = |l: &str| l == "at shoulder level" || l == "above shoulder";
+            let wr_dist = mag(sub(p.left_wrist.xyz(), p.right_wrist.xyz()));
+            let l_ang = angle_at(p.left_shoulder.xyz(),  p.left_elbow.xyz(),  p.left_wrist.xyz());
+            let r_ang = angle_at(p.right_shoulder.xyz(), p.right_elbow.xyz(), p.right_wrist.xyz());
+            if high(l_lvl) && high(r_lvl) && wr_dist < m.shoulder_w * 0.6 {
+                return Some("reaching forward with both hands".into());
+            }
+            if l_lvl == r_lvl && wr_dist >= m.shoulder_w * 0.6 {
+                if high(l_lvl) && l_ang > 160.0 && r_ang > 160.0 {
+                    return Some("arms held straight out in front".into());
+                }
+                if l_lvl == "at chest level" && l_ang > 140.0 && r_ang > 140.0 {
+                    return Some("arms extended forward as if presenting".into());
+                }
+            }
+        }
+    }
+
     // Symmetric collapse — only works when both arms produce the same base label.
     // The level qualifiers attached to some labels prevent exact matches when
     // the arms are at different heights, which is the correct behaviour.
@@ -592,7 +1371,8 @@ fn arms(p: &Pose, m: &BodyMetrics) -> Option<String> {
         ("left arm extended forward",         "right arm extended forward",         "arms extended forward"),
         ("left arm extended forward-outward", "right arm extended forward-outward", "arms extended forward-outward"),
         ("left arm reaching forward",         "right arm reaching forward",         "arms reaching forward"),
-        ("left arm pointing forward",         "right arm pointing forward",         "arms pointing forward"),
+        ("left arm pointing toward viewer",   "right arm pointing toward viewer",   "arms pointing toward viewer"),
+        ("left arm pointing away from viewer", "right arm pointing away from viewer", "arms pointing away from viewer"),
         ("left arm outstretched sideways",    "right arm outstretched sideways",    "arms outstretched sideways"),
         ("left arm crossed",                  "right arm crossed",                  "arms crossed"),
         ("left arm behind back",              "right arm behind back",              "arms behind back"),
@@ -627,10 +1407,10 @@ fn describe_arm(sh: V3, el: V3, wr: V3, head: V3, side: &str, m: &BodyMetrics) -
     // Body-relative unit components of the shoulder→wrist direction:
     //   up  : +1 = wrist straight above shoulder
     //   out : +1 = wrist directly to the side (away from body centre)
-    //   fwd : +1 = wrist into scene = character's forward
+    //   fwd : +1 = wrist toward the viewer = character's forward
     let up  = -sw.1 / sw_m;
     let out =  sw.0 * sign / sw_m;
-    let fwd =  sw.2 / sw_m; // +1 = into scene = character's forward
+    let fwd =  sw.2 / sw_m; // +1 = character's forward = toward the Front camera
 
     // atan2-based angles for more precise directional classification.
     //   horiz_angle : angle in the horizontal plane measured from forward axis.
@@ -674,14 +1454,28 @@ fn describe_arm(sh: V3, el: V3, wr: V3, head: V3, side: &str, m: &BodyMetrics) -
                       else if out > 0.0 { " outward" } else { "" };
             return Some(format!("{side} arm pointing up{dir}"));
         }
+        // Angled down and away from the body — gesturing at the ground. Steeper
+        // than −55° is a hanging or splayed arm; shallower than −40° still
+        // reads as a level point at chest or shoulder height.
+        if (-55.0..-40.0).contains(&elev_angle) {
+            let dir = if horiz_angle.abs() > 45.0 && out > 0.0 { " and outward" } else { "" };
+            return Some(format!("{side} arm pointing down{dir}"));
+        }
+        // A point is a gesture at something, so name its target in camera terms
+        // rather than the character's: Z positive = toward viewer (see header).
         if fwd > 0.55 {
             let level = m.level_name(wr.1);
-            return Some(format!("{side} arm pointing forward {level}"));
+            return Some(format!("{side} arm pointing toward viewer {level}"));
+        }
+        if fwd < -0.55 {
+            let level = m.level_name(wr.1);
+            return Some(format!("{side} arm pointing away from viewer {level}"));
         }
         if out > 0.55 {
             let level = m.level_name(wr.1);
             return Some(format!("{side} arm pointing sideways {level}"));
         }
+        // Aimed back past the torso on a diagonal, neither clearly away nor out.
         if fwd < -0.45 {
             return Some(format!("{side} arm pointing behind"));
         }
@@ -748,7 +1542,7 @@ fn describe_arm(sh: V3, el: V3, wr: V3, head: V3, side: &str, m: &BodyMetrics) -
             // Determine which part of the head the hand is near using Y and Z offsets.
             let wr_above_head = wr.1 < head.1 - m.torso_h * 0.08; // wrist above head centre
             let wr_at_chin    = wr.1 > head.1 + m.torso_h * 0.06; // wrist below head centre (chin)
-            let wr_fwd_of_head = wr.2 < head.2 - 10.0;            // wrist toward viewer = covering face
+            let wr_fwd_of_head = wr.2 > head.2 + 10.0;            // wrist toward viewer = covering face
             return Some(if wr_above_head {
                 format!("{side} hand on top of head")
             } else if wr_at_chin {
@@ -796,7 +1590,7 @@ fn describe_arm(sh: V3, el: V3, wr: V3, head: V3, side: &str, m: &BodyMetrics) -
 
 // ─── Legs ─────────────────────────────────────────────────────────────────────
 
-fn legs(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Option<String> {
+fn legs(p: &Pose, m: &BodyMetrics, stance_str: &str, opts: &DescribeOptions) -> Option<String> {
     // ── Early exit: stance already owns the lower-body description ────────────
     // These postures are fully characterised by stance(); appending per-leg detail
     // would be redundant or directly contradict the primary description.
@@ -810,6 +1604,7 @@ fn legs(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Option<String> {
         || stance_str.contains("knee raised")
         || stance_str.contains("splits")
         || stance_str.contains("tip-toe")
+        || stance_str.contains("figure-4")
     {
         return None;
     }
@@ -824,8 +1619,8 @@ fn legs(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Option<String> {
     if spread_ratio >= 0.90 {
         let width = if spread_ratio >= 1.60 { "very wide" } else { "wide" };
         // Still describe stride within a wide stance
-        let l = describe_leg(p.crotch.xyz(), p.left_knee.xyz(),  p.left_ankle.xyz(),  "left",  m);
-        let r = describe_leg(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz(), "right", m);
+        let l = describe_leg(p.crotch.xyz(), p.left_knee.xyz(),  p.left_ankle.xyz(),  "left",  m, opts);
+        let r = describe_leg(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz(), "right", m, opts);
         let stride = symmetrize(&l, &r, &[
             ("left leg forward", "right leg back",    "legs in stride"),
             ("left leg back",    "right leg forward", "legs in stride"),
@@ -836,16 +1631,29 @@ fn legs(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Option<String> {
         });
     }
 
-    let left  = describe_leg(p.crotch.xyz(), p.left_knee.xyz(),  p.left_ankle.xyz(),  "left",  m);
-    let right = describe_leg(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz(), "right", m);
+    let left  = describe_leg(p.crotch.xyz(), p.left_knee.xyz(),  p.left_ankle.xyz(),  "left",  m, opts);
+    let right = describe_leg(p.crotch.xyz(), p.right_knee.xyz(), p.right_ankle.xyz(), "right", m, opts);
+
+    let l_straight = left.as_deref().map_or(false,  |s| s.contains("straight") || s.contains("slightly bent"));
+    let r_straight = right.as_deref().map_or(false, |s| s.contains("straight") || s.contains("slightly bent"));
+
+    // ── Legs crossed at the thigh ─────────────────────────────────────────────
+    // Both legs roughly straight with the left knee clearly past the right and
+    // the ankles crossed the same way — the casual standing cross. Knees alone
+    // drifting together is just a narrow stance; ankles alone are handled below.
+    {
+        let knees_crossed = p.left_knee.x > p.right_knee.x + m.shoulder_w * 0.1;
+        let ankles_too    = p.left_ankle.x > p.right_ankle.x;
+        if knees_crossed && ankles_too && l_straight && r_straight {
+            return Some("legs crossed at the thigh".into());
+        }
+    }
 
     // ── Crossed ankles (standing rest pose) ───────────────────────────────────
     // Left ankle has drifted right of the right ankle — ankles crossed.
     // Only meaningful when both legs are mostly straight (not a lunge/step already described).
     {
         let ankles_crossed = p.left_ankle.x > p.right_ankle.x + 8.0;
-        let l_straight = left.as_deref().map_or(false,  |s| s.contains("straight") || s.contains("slightly bent"));
-        let r_straight = right.as_deref().map_or(false, |s| s.contains("straight") || s.contains("slightly bent"));
         if ankles_crossed && l_straight && r_straight {
             return Some("ankles crossed".into());
         }
@@ -919,13 +1727,13 @@ fn legs(p: &Pose, m: &BodyMetrics, stance_str: &str) -> Option<String> {
 ///
 /// Body-relative frame (sign flipped for left side so "outward" is always +):
 ///   up  : +1 = ankle above hip
-///   fwd : +1 = ankle into scene = character's forward
+///   fwd : +1 = ankle toward the viewer = character's forward
 ///   lat : +1 = ankle away from body centre (outward)
 ///
 /// Knee deviation is measured perpendicular to the hip→ankle line:
 ///   knee_dev > 0 = knee bowed outward (varus)
 ///   knee_dev < 0 = knee caved inward  (valgus)
-fn describe_leg(hip: V3, kn: V3, an: V3, side: &str, m: &BodyMetrics) -> Option<String> {
+fn describe_leg(hip: V3, kn: V3, an: V3, side: &str, m: &BodyMetrics, opts: &DescribeOptions) -> Option<String> {
     let sign: f32 = if side == "right" { 1.0 } else { -1.0 };
 
     let ha   = sub(an, hip);
@@ -933,7 +1741,7 @@ fn describe_leg(hip: V3, kn: V3, an: V3, side: &str, m: &BodyMetrics) -> Option<
     if ha_m < 1.0 { return None; }
 
     let up  = -ha.1 / ha_m;           // +1 = ankle above hip
-    let fwd =  ha.2 / ha_m;           // +1 = into scene = character's forward
+    let fwd =  ha.2 / ha_m;           // +1 = character's forward (toward the viewer)
     let lat =  ha.0 * sign / ha_m;    // +1 = ankle outward (away from centre)
     let bend = angle_at(hip, kn, an); // angle at the knee; 180 = straight
 
@@ -955,12 +1763,13 @@ fn describe_leg(hip: V3, kn: V3, an: V3, side: &str, m: &BodyMetrics) -> Option<
 
     // ── Shin direction (ankle relative to knee in Z) ──────────────────────────
     // Useful for distinguishing a deep squat (shin vertical) from a lunge (shin forward).
-    let shin_fwd  = an.2 - kn.2 > 20.0; // ankle further into scene than knee → shin forward
-    let shin_back = kn.2 - an.2 > 20.0; // ankle closer to viewer than knee → shin angled back
+    let shin_fwd  = an.2 - kn.2 > 20.0; // ankle further forward than knee → shin forward
+    let shin_back = kn.2 - an.2 > 20.0; // ankle further back than knee → shin angled back
 
     // ── Ankle clearly above hip (leg raised / kicked) ─────────────────────────
     if elev > 17.0 {  // atan2: ~17° corresponds to up ≈ 0.30 of ha_m
-        let h   = m.foot_raise_desc(an.1);
+        let h   = if opts.verbose { format!("raised ~{:.0}°", raise_angle(hip, an)) }
+                  else { m.foot_raise_desc(an.1).to_string() };
         // Use h_angle bands for cleaner directional blends
         let dir = if h_angle.abs() < 55.0   { " forward" }
                   else if h_angle.abs() > 125.0 { " behind" }
@@ -1054,4 +1863,138 @@ fn symmetrize_prefix(left: &Option<String>, right: &Option<String>,
         }
     }
     None
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `Pose::from_points` with coordinates in head units (crotch at the
+    /// origin, Y down, +Z forward) so cases read like the skeleton proportions.
+    fn pose(points: &[(&str, (f32, f32, f32))]) -> Pose {
+        let h = crate::skeleton::get().head_size;
+        let scaled: Vec<_> = points.iter().map(|&(n, (x, y, z))| (n, (x * h, y * h, z * h))).collect();
+        Pose::from_points(&scaled)
+    }
+
+    #[test]
+    fn stance_standing() {
+        assert_eq!(classify_stance(&pose(&[])), "standing, feet hip-width apart");
+    }
+
+    #[test]
+    fn stance_seated() {
+        // Thighs level and forward, shins straight down to the floor.
+        let p = pose(&[
+            ("left_knee",  (-0.4, 0.0, 2.25)), ("right_knee",  (0.4, 0.0, 2.25)),
+            ("left_ankle", (-0.4, 2.25, 2.25)), ("right_ankle", (0.4, 2.25, 2.25)),
+        ]);
+        assert_eq!(classify_stance(&p), "seated");
+    }
+
+    #[test]
+    fn stance_kneeling() {
+        // Knees on the floor under the hips, shins trailing back (−Z).
+        let p = pose(&[
+            ("left_ankle", (-0.4, 2.25, -2.25)), ("right_ankle", (0.4, 2.25, -2.25)),
+        ]);
+        assert_eq!(classify_stance(&p), "kneeling");
+    }
+
+    #[test]
+    fn stance_kneeling_torso_forward() {
+        let p = pose(&[
+            ("head", (0.0, -2.5, 1.7)), ("neck", (0.0, -2.1, 1.3)),
+            ("left_shoulder", (-1.0, -2.1, 1.3)), ("right_shoulder", (1.0, -2.1, 1.3)),
+            ("left_ankle", (-0.4, 2.25, -2.25)), ("right_ankle", (0.4, 2.25, -2.25)),
+        ]);
+        assert_eq!(classify_stance(&p), "kneeling, torso leaning forward");
+    }
+
+    #[test]
+    fn stance_one_knee_down() {
+        // Left knee on the floor with its shin back, right foot planted ahead.
+        let p = pose(&[
+            ("left_ankle", (-0.4, 2.25, -2.25)),
+            ("right_knee",  (0.4, 0.0, 2.25)), ("right_ankle", (0.4, 2.25, 2.25)),
+        ]);
+        assert_eq!(classify_stance(&p), "kneeling on one knee, other foot forward (proposal stance)");
+    }
+
+    #[test]
+    fn stance_squat() {
+        // Knees out to the sides rather than forward, hips low.
+        let p = pose(&[
+            ("left_knee",  (-1.6, -0.2, 0.3)), ("right_knee",  (1.6, -0.2, 0.3)),
+            ("left_ankle", (-1.6, 1.6, 0.3)),  ("right_ankle", (1.6, 1.6, 0.3)),
+        ]);
+        assert!(classify_stance(&p).ends_with("squat"), "{}", classify_stance(&p));
+    }
+
+    #[test]
+    fn stance_side_splits() {
+        let p = pose(&[
+            ("left_knee",  (-2.2, 0.1, 0.0)), ("right_knee",  (2.2, 0.1, 0.0)),
+            ("left_ankle", (-4.5, 0.2, 0.0)), ("right_ankle", (4.5, 0.2, 0.0)),
+        ]);
+        assert_eq!(classify_stance(&p), "doing the side splits");
+    }
+
+    #[test]
+    fn stance_forward_splits() {
+        let p = pose(&[
+            ("left_knee",  (-0.4, 0.1, 2.2)),  ("right_knee",  (0.4, 0.1, -2.2)),
+            ("left_ankle", (-0.4, 0.2, 4.5)),  ("right_ankle", (0.4, 0.2, -4.5)),
+        ]);
+        assert_eq!(classify_stance(&p), "doing the forward splits, left leg forward");
+    }
+
+    #[test]
+    fn stance_balancing() {
+        // Straight left leg swung forward and up, right leg planted.
+        let p = pose(&[
+            ("left_knee", (-0.4, 1.6, 1.6)), ("left_ankle", (-0.4, 3.2, 3.2)),
+        ]);
+        assert!(classify_stance(&p).starts_with("balancing on right leg, left foot"),
+                "{}", classify_stance(&p));
+    }
+
+    #[test]
+    fn stance_lying_face_up() {
+        // The standing pose laid flat, head toward −Z.
+        let h = crate::skeleton::get().head_size;
+        let flat: Vec<_> = pose(&[]).joints_ordered()
+            .map(|(n, j)| (n, (j.x / h, 0.0, j.y / h))).collect();
+        assert_eq!(classify_stance(&pose(&flat)), "lying face up");
+    }
+
+    #[test]
+    fn akimbo_with_neutral_other_arm() {
+        // Left hand on the hip with the elbow out, right arm hanging at rest.
+        let hip = [("left_elbow", (-2.2, -1.2, 0.0)), ("left_wrist", (-1.6, -0.2, 0.0))];
+        let p = pose(&hip);
+        assert_eq!(arms(&p, &BodyMetrics::new(&p)).as_deref(),
+                   Some("left hand on hip, right arm at side"));
+        // Right wrist folded back onto the shoulder: describe_arm returns None,
+        // and the hand on hip must still be reported on its own.
+        let p = pose(&[hip[0], hip[1], ("right_wrist", (1.0, -2.5, 0.0))]);
+        assert_eq!(arms(&p, &BodyMetrics::new(&p)).as_deref(), Some("left hand on hip"));
+    }
+
+    #[test]
+    fn torso_lean_follows_plus_z_forward() {
+        // Upper body shifted along Z over the hips; +Z is the character's forward.
+        let lean = |z: f32| {
+            let p = pose(&[
+                ("head", (0.0, -3.0, z * 1.2)), ("neck", (0.0, -2.5, z)),
+                ("left_shoulder", (-1.0, -2.5, z)), ("right_shoulder", (1.0, -2.5, z)),
+                ("left_elbow", (-1.0, -1.0, z)), ("right_elbow", (1.0, -1.0, z)),
+                ("left_wrist", (-1.0, 0.2, z)), ("right_wrist", (1.0, 0.2, z)),
+            ]);
+            torso_lean(&p, config()).unwrap_or_default()
+        };
+        assert!(lean(1.5).contains("forward"), "{}", lean(1.5));
+        assert!(lean(-1.5).contains("back"), "{}", lean(-1.5));
+        assert_eq!(lean(0.0), "");
+    }
+}
@@ -36,6 +36,23 @@ pub struct Constraints {
 fn default_elbow() -> AngleRange { AngleRange { min: 30.0, max: 180.0 } }
 fn default_knee()  -> AngleRange { AngleRange { min: 30.0, max: 180.0 } }
 
+// FABRIK solver tuning. More iterations = limbs track fast reaches more
+// precisely; fewer = cheaper per drag event on slow machines.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SolverConfig {
+    #[serde(default = "default_iterations")]
+    pub iterations: usize,
+    #[serde(default = "default_tolerance")]
+    pub tolerance: f32,
+}
+
+fn default_iterations() -> usize { 6 }
+fn default_tolerance()  -> f32   { 0.001 }
+
+impl Default for SolverConfig {
+    fn default() -> Self { Self { iterations: default_iterations(), tolerance: default_tolerance() } }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Skeleton {
     pub head_size: f32,
@@ -44,6 +61,8 @@ pub struct Skeleton {
     pub joints:    Vec<JointDef>,
     #[allow(dead_code)]
     pub constraints: Constraints,
+    #[serde(default)]
+    pub solver:    SolverConfig,
 }
 
 impl Skeleton {
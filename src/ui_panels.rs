@@ -1,6 +1,6 @@
 // ui_panels.rs
 use std::sync::Arc;
-use egui::{Ui, CollapsingHeader, ComboBox, Grid, Slider, ScrollArea};
+use egui::{Ui, ComboBox, Grid, Slider, ScrollArea};
 use crate::app::{PresetItem, PresetMetadata, PromptPuppetApp};
 use crate::json_loader::{OptionCategory, UiConfig, PanelConfig};
 
@@ -8,10 +8,21 @@ pub fn render_ui_from_config(app: &mut PromptPuppetApp, ui: &mut Ui, config: &Ui
     config.panels.iter().fold(false, |ch, panel| {
         ui.add_space(2.0);
         let changed = egui::Frame::NONE.inner_margin(egui::Margin::symmetric(4, 2)).show(ui, |ui| {
-            CollapsingHeader::new(egui::RichText::new(&panel.title).strong())
-                .default_open(panel.default_open)
-                .show(ui, |ui| { ui.add_space(4.0); let c = render_panel(app, ui, panel); ui.add_space(4.0); c })
-                .body_returned.unwrap_or(false)
+            // Header checkbox: does this panel contribute to the prompt? Unticking
+            // keeps the panel's selections, so it can be flipped back for A/B tests.
+            let id = ui.make_persistent_id(("panel", &panel.title));
+            let (_, muted, body) = egui::collapsing_header::CollapsingState::load_with_default_open(
+                ui.ctx(), id, panel.default_open)
+                .show_header(ui, |ui| {
+                    let mut on = !app.state.muted_sections.contains(&panel.title);
+                    let toggled = ui.checkbox(&mut on, "").on_hover_text("Include in prompt").changed();
+                    if toggled && on { app.state.muted_sections.remove(&panel.title); }
+                    if toggled && !on { app.state.muted_sections.insert(panel.title.clone()); }
+                    ui.label(egui::RichText::new(&panel.title).strong());
+                    toggled
+                })
+                .body(|ui| { ui.add_space(4.0); let c = render_panel(app, ui, panel); ui.add_space(4.0); c });
+            muted.inner | body.is_some_and(|b| b.inner)
         }).inner;
         ui.separator();
         ch | changed
@@ -28,7 +39,11 @@ fn render_panel(app: &mut PromptPuppetApp, ui: &mut Ui, panel: &PanelConfig) ->
             ui.label(&comp.label);
             ch | render_component(ui, comp.data_source.trim_end_matches(".json"), &comp.component_type, app)
         }),
-        "sequence" => if app.state.video_mode { render_sequence_panel(ui, app) } else { false },
+        "head_controls"   => render_head_panel(ui, app),
+        "sequence" => if app.state.video_mode { render_sequence_panel(ui, app) } else {
+            ui.label(egui::RichText::new("Enable 🎬 Video Mode to edit sequences.").small().color(ui.visuals().weak_text_color()));
+            false
+        },
         _ => false,
     }
 }
@@ -216,6 +231,7 @@ fn render_preset_selector(ui: &mut Ui, key: &str, app: &mut PromptPuppetApp) ->
             }
             if ui.button("✖").clicked() {
                 app.search.entry(key.to_string()).or_default().clear();
+                app.tag_filter.remove(key);
                 popup_open = false;
                 *app.popup_open.get_mut(key).unwrap() = false;
             }
@@ -260,12 +276,25 @@ fn render_preset_selector(ui: &mut Ui, key: &str, app: &mut PromptPuppetApp) ->
         }
     }
 
+    // ── Tag filter ────────────────────────────────────────────────────────────
+    // The most common tags become chips at the top of the popup; rarer ones are
+    // still reachable by typing them into the search box.
+    let mut tag_counts: std::collections::HashMap<&str, usize> = Default::default();
+    for t in items.iter().flat_map(|i| &i.tags) { *tag_counts.entry(t.as_str()).or_default() += 1; }
+    let mut top_tags: Vec<_> = tag_counts.into_iter().collect();
+    top_tags.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    top_tags.truncate(16);
+    let active_tag = app.tag_filter.get(key).cloned();
+    let mut new_tag: Option<Option<String>> = None;
+
     // ── Ranked items ──────────────────────────────────────────────────────────
     let query = app.search.get(key).map(|s| s.to_lowercase()).unwrap_or_default();
     let mut ranked: Vec<_> = items.iter()
-        .filter_map(|item| search_rank(&item.name, item.prompt.as_deref().unwrap_or(""), &query).map(|s| (s, item)))
+        .filter(|item| active_tag.as_ref().is_none_or(|t| item.tags.contains(t)))
+        .filter_map(|item| search_rank(&item.name, item.prompt.as_deref().unwrap_or(""), &item.tags, &query).map(|s| (s, item)))
         .collect();
     ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    let browsing = query.is_empty() && active_tag.is_none();
 
     // ── Popup ─────────────────────────────────────────────────────────────────
     let mut should_close = false;
@@ -276,6 +305,18 @@ fn render_preset_selector(ui: &mut Ui, key: &str, app: &mut PromptPuppetApp) ->
         .close_behavior(egui::PopupCloseBehavior::CloseOnClickOutside)
         .show(|ui| {
             ui.set_min_width(300.0);
+            if !top_tags.is_empty() {
+                ui.horizontal_wrapped(|ui| {
+                    ui.label("🏷");
+                    for (t, _) in &top_tags {
+                        let on = active_tag.as_deref() == Some(*t);
+                        if ui.selectable_label(on, egui::RichText::new(*t).small()).clicked() {
+                            new_tag = Some((!on).then(|| t.to_string()));
+                        }
+                    }
+                });
+                ui.separator();
+            }
             ScrollArea::vertical().max_height(340.0).auto_shrink([false, false]).show(ui, |ui| {
                 if use_grid {
                     Grid::new(format!("{}_grid", key)).num_columns(3).spacing([4.0, 4.0]).show(ui, |ui| {
@@ -286,6 +327,29 @@ fn render_preset_selector(ui: &mut Ui, key: &str, app: &mut PromptPuppetApp) ->
                             ch | (clicked && handle_selection(app, key, &item.id, &items, meta.as_ref()))
                         })
                     }).inner
+                } else if browsing && items.iter().any(|i| i.category.is_some()) {
+                    // Nothing typed or filtered: show the library's own categories as
+                    // collapsible sections, opening the ones holding a selection.
+                    let mut groups: Vec<(Option<&str>, Vec<&PresetItem>)> = Vec::new();
+                    for (_, item) in &ranked {
+                        let cat = item.category.as_deref();
+                        match groups.iter_mut().find(|(c, _)| *c == cat) {
+                            Some((_, g)) => g.push(item),
+                            None => groups.push((cat, vec![item])),
+                        }
+                    }
+                    groups.into_iter().fold(false, |ch, (cat, group)| {
+                        let open = group.iter().any(|i| selected.contains(&i.id));
+                        let title = format!("{} ({})", cat.unwrap_or("Other"), group.len());
+                        ch | egui::CollapsingHeader::new(title).id_salt((key, cat)).default_open(open)
+                            .show(ui, |ui| group.iter().fold(false, |ch, item| {
+                                let clicked = render_item(ui, item, selected.contains(&item.id), just_opened);
+                                if clicked { should_close = true; if !allow_multi { should_clear = true; } }
+                                ui.separator();
+                                ch | (clicked && handle_selection(app, key, &item.id, items, meta.as_ref()))
+                            }))
+                            .body_returned.unwrap_or(false)
+                    })
                 } else {
                     ranked.iter().fold(false, |ch, (_, item)| {
                         let clicked = render_item(ui, item, selected.contains(&item.id), just_opened);
@@ -300,6 +364,11 @@ fn render_preset_selector(ui: &mut Ui, key: &str, app: &mut PromptPuppetApp) ->
         .unwrap_or_else(|| { popup_open = false; false });
 
     if should_clear { app.search.entry(key.to_string()).or_default().clear(); }
+    match new_tag {
+        Some(Some(t)) => { app.tag_filter.insert(key.to_string(), t); }
+        Some(None)    => { app.tag_filter.remove(key); }
+        None          => {}
+    }
     app.popup_open.insert(key.to_string(), popup_open);
     changed
 }
@@ -345,21 +414,55 @@ fn handle_selection(app: &mut PromptPuppetApp, key: &str, id: &str,
 }
 
 fn update_pose(app: &mut PromptPuppetApp, id: &str, items: &[PresetItem]) {
-    if let Some(pose) = items.iter().find(|i| i.id == id).and_then(|i| i.pose_data.clone()) {
+    let Some(item) = items.iter().find(|i| i.id == id) else { return };
+    if let Some(pose) = item.pose_data.clone() {
+        app.stash_before_preset(&pose);
         app.state.pose = pose;
+        app.state.pose_origin = Some(id.to_string());
         app.pose_is_manual = false;
     }
+    // Presets may suggest a view; otherwise the camera stays where the user left it.
+    if let Some(c) = item.camera {
+        app.camera_3d.yaw   = c.yaw.to_radians();
+        app.camera_3d.pitch = c.pitch.to_radians();
+        if let Some(r) = c.radius { app.camera_3d.radius = r; }
+    }
 }
 
-fn search_rank(name: &str, prompt: &str, query: &str) -> Option<u8> {
+fn search_rank(name: &str, prompt: &str, tags: &[String], query: &str) -> Option<u8> {
     if query.is_empty() { return Some(255); }
     let n = name.to_lowercase();
     if n.starts_with(query)           { Some(3) }
-    else if n.contains(query)         { Some(2) }
+    else if n.contains(query) || tags.iter().any(|t| t.to_lowercase().contains(query)) { Some(2) }
     else if prompt.to_lowercase().contains(query) { Some(1) }
     else                              { None }
 }
 
+/// Exact nod / yaw / roll for the head, in the same degrees `head_orient`
+/// reads. Nod and yaw also swing the head joint so the canvas follows.
+fn render_head_panel(ui: &mut Ui, app: &mut PromptPuppetApp) -> bool {
+    let pose = &app.state.pose;
+    let (mut nod, mut yaw, mut tilt) = (pose.head_nod, pose.head_yaw, pose.head_tilt);
+    let mut changed = false;
+    Grid::new("head_orientation").num_columns(2).spacing([8.0, 4.0]).show(ui, |ui| {
+        for (label, v, range, hint) in [
+            ("Nod",  &mut nod,  -60.0..=60.0, "+ chin down · − looking up"),
+            ("Yaw",  &mut yaw,  -90.0..=90.0, "+ turned to the character's right"),
+            ("Tilt", &mut tilt, -45.0..=45.0, "+ ear toward the right shoulder"),
+        ] {
+            ui.label(label);
+            changed |= ui.add(Slider::new(v, range).suffix("°")).on_hover_text(hint).changed();
+            ui.end_row();
+        }
+    });
+    if ui.button("↺ Face forward").clicked() { (nod, yaw, tilt) = (0.0, 0.0, 0.0); changed = true; }
+    if changed {
+        app.state.pose.set_head_orientation(nod, yaw, tilt, crate::skeleton::get());
+        app.pose_is_manual = true;
+    }
+    changed
+}
+
 pub fn render_sequence_panel(ui: &mut Ui, app: &mut PromptPuppetApp) -> bool {
     let keys: Vec<_> = app.state.selections.keys().cloned().collect();
     let mut changed = keys.iter().fold(false, |ch, key| {
@@ -378,5 +481,143 @@ pub fn render_sequence_panel(ui: &mut Ui, app: &mut PromptPuppetApp) -> bool {
         for s in app.state.selections.values_mut() { s.sequence.clear(); }
         changed = true;
     }
+    ui.separator();
+    changed | render_keyframe_timeline(ui, app)
+}
+
+// ── Keyframe timeline ─────────────────────────────────────────────────────────
+
+enum KeyframeAction { Remove(usize), Swap(usize, usize), Jump(usize) }
+
+fn render_keyframe_timeline(ui: &mut Ui, app: &mut PromptPuppetApp) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        ui.label(egui::RichText::new("Pose Keyframes").strong());
+        if ui.button("➕ Add Current Pose").clicked() {
+            app.state.keyframes.push(app.state.pose.clone());
+            app.timeline_pos = (app.state.keyframes.len() - 1) as f32;
+            app.set_status(&format!("✅ Keyframe #{}", app.state.keyframes.len()), 2.0);
+            changed = true;
+        }
+    });
+    // ── Procedural loops: preview, or bake one cycle into keyframes ──────────
+    ui.horizontal(|ui| {
+        let mut choice = app.loop_choice;
+        ComboBox::from_id_salt("loop_anim").selected_text(choice.label()).show_ui(ui, |ui| {
+            for a in crate::ftlz::LoopAnim::VIDEO {
+                ui.selectable_value(&mut choice, a, a.label());
+            }
+        });
+        app.loop_choice = choice;
+        let playing = app.dance_mode && app.loop_anim == choice;
+        if ui.button(if playing { "⏹ Stop" } else { "▶ Play" }).clicked() {
+            if app.dance_mode { app.stop_dance(); }
+            if !playing { app.start_loop(choice); }
+        }
+        if ui.button("🎞 Bake").on_hover_text("Append one cycle of this loop as 4 keyframes").clicked() {
+            let base = app.pre_dance_pose.clone().unwrap_or_else(|| app.state.pose.clone());
+            for i in 0..4 {
+                let mut kf = base.clone();
+                choice.apply(&mut kf, &base, choice.period() * i as f32 / 4.0);
+                app.state.keyframes.push(kf);
+            }
+            app.timeline_pos = (app.state.keyframes.len() - 1) as f32;
+            app.set_status(&format!("✅ Baked {} into 4 keyframes", choice.label()), 2.0);
+            changed = true;
+        }
+    });
+
+    let n = app.state.keyframes.len();
+    if n == 0 {
+        ui.label(egui::RichText::new("No keyframes yet.").small().color(ui.visuals().weak_text_color()));
+        return changed;
+    }
+
+    let mut action = None;
+    ScrollArea::horizontal().id_salt("keyframe_strip").show(ui, |ui| {
+        ui.horizontal(|ui| {
+            for (i, kf) in app.state.keyframes.iter().enumerate() {
+                ui.vertical(|ui| {
+                    let active = (app.timeline_pos - i as f32).abs() < 0.01;
+                    if draw_pose_thumbnail(ui, kf, egui::vec2(48.0, 64.0), active).clicked() {
+                        action = Some(KeyframeAction::Jump(i));
+                    }
+                    ui.horizontal(|ui| {
+                        ui.spacing_mut().item_spacing.x = 2.0;
+                        if ui.add_enabled(i > 0, egui::Button::new("◀").small()).clicked() {
+                            action = Some(KeyframeAction::Swap(i, i - 1));
+                        }
+                        ui.label(egui::RichText::new(format!("#{}", i + 1)).small());
+                        if ui.add_enabled(i + 1 < n, egui::Button::new("▶").small()).clicked() {
+                            action = Some(KeyframeAction::Swap(i, i + 1));
+                        }
+                    });
+                    if ui.small_button("❌").clicked() { action = Some(KeyframeAction::Remove(i)); }
+                });
+            }
+        });
+    });
+    match action {
+        Some(KeyframeAction::Remove(i)) => {
+            app.state.keyframes.remove(i);
+            app.timeline_pos = app.timeline_pos.min(app.state.keyframes.len().saturating_sub(1) as f32);
+            changed = true;
+        }
+        Some(KeyframeAction::Swap(a, b)) => { app.state.keyframes.swap(a, b); changed = true; }
+        Some(KeyframeAction::Jump(i)) => {
+            app.timeline_pos = i as f32;
+            app.state.pose = app.state.keyframes[i].clone();
+            app.pose_is_manual = true;
+            changed = true;
+        }
+        None => {}
+    }
+
+    // ── Scrub with interpolation between keyframes ────────────────────────────
+    let n = app.state.keyframes.len();
+    if n >= 2 {
+        let scrub = ui.add(Slider::new(&mut app.timeline_pos, 0.0..=(n - 1) as f32).text("Scrub"));
+        if scrub.changed() {
+            if let Some(pose) = crate::pose::Pose::sample(&app.state.keyframes, app.timeline_pos) {
+                app.state.pose = pose;
+                app.pose_is_manual = true;
+            }
+            changed = true;
+        }
+    }
+    if ui.button("Clear Keyframes").clicked() {
+        app.state.keyframes.clear();
+        app.timeline_pos = 0.0;
+        changed = true;
+    }
     changed
+}
+
+/// Front-view stick-figure thumbnail, fitted to `size`. Returns the click response.
+fn draw_pose_thumbnail(ui: &mut Ui, pose: &crate::pose::Pose, size: egui::Vec2, active: bool) -> egui::Response {
+    let (resp, p) = ui.allocate_painter(size, egui::Sense::click());
+    let r = resp.rect;
+    let v = ui.visuals();
+    p.rect_filled(r, 4.0, v.extreme_bg_color);
+    let edge = if active { v.selection.stroke } else { v.widgets.noninteractive.bg_stroke };
+    p.rect_stroke(r, 4.0, edge, egui::StrokeKind::Inside);
+
+    let sk  = crate::skeleton::get();
+    let get = |name: &str| pose.joint(name);
+    let pts: Vec<_> = sk.joints.iter().filter_map(|jd| get(&jd.name)).collect();
+    let (min_x, max_x) = pts.iter().fold((f32::MAX, f32::MIN), |(lo, hi), j| (lo.min(j.x), hi.max(j.x)));
+    let (min_y, max_y) = pts.iter().fold((f32::MAX, f32::MIN), |(lo, hi), j| (lo.min(j.y), hi.max(j.y)));
+    let inner = r.shrink(5.0);
+    let scale = (inner.width() / (max_x - min_x).max(1.0)).min(inner.height() / (max_y - min_y).max(1.0));
+    let mid   = egui::pos2((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+    let to_screen = |j: &crate::pose::Joint| inner.center() + egui::vec2(j.x - mid.x, j.y - mid.y) * scale;
+    for bone in &sk.bones {
+        if let (Some(a), Some(b)) = (get(&bone.a), get(&bone.b)) {
+            p.line_segment([to_screen(a), to_screen(b)], egui::Stroke::new(1.5, crate::skeleton::color32(bone.color)));
+        }
+    }
+    if let Some(jd) = sk.joints.iter().find(|j| j.name == "head") {
+        p.circle_filled(to_screen(&pose.head), 2.5, crate::skeleton::color32(jd.color));
+    }
+    resp
 }
\ No newline at end of file
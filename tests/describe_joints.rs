@@ -0,0 +1,31 @@
+// Headless use of the pose → text engine through the library API.
+
+use std::collections::HashMap;
+
+fn points(list: &[(&str, [f32; 3])]) -> HashMap<String, [f32; 3]> {
+    list.iter().map(|&(k, v)| (k.to_string(), v)).collect()
+}
+
+#[test]
+fn describes_pose_without_touching_app_dir() {
+    // Point the app directory somewhere empty: the library must read only the
+    // embedded assets and never create <home>/.config/PromptPuppet.
+    let home = std::env::temp_dir().join(format!("pp_headless_{}", std::process::id()));
+    std::env::set_var("HOME", &home);
+    std::env::set_var("APPDATA", &home);
+
+    // The "Military attention" preset from poses.json.
+    let attention = points(&[
+        ("head",          [0.0, 7.2019, 0.0]),     ("neck",           [0.0, 6.7019, 0.0]),
+        ("pelvis",        [0.0, 4.0, 0.0]),
+        ("left_shoulder", [-0.6654, 7.189, 0.0]),  ("right_shoulder", [0.6654, 7.189, 0.0]),
+        ("left_elbow",    [-1.4899, 5.699, 0.0]),  ("right_elbow",    [1.4899, 5.699, 0.0]),
+        ("left_knee",     [-0.3715, 1.4814, 0.0]), ("right_knee",     [0.3715, 1.4814, 0.0]),
+    ]);
+    let text = prompt_puppet::describe_joints(&attention);
+    assert!(text.contains("arms at sides"), "{text}");
+    let structured = prompt_puppet::describe_joints_structured(&attention).expect("pose from points");
+    assert_eq!(structured.stance, "standing, feet together");
+
+    assert!(!home.exists(), "headless describe created {}", home.display());
+}